@@ -0,0 +1,71 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Ident, LitInt, LitStr, Result};
+
+pub fn joker_info(input: DeriveInput) -> Result<TokenStream> {
+    let mut id = None::<LitStr>;
+    let mut name = None::<LitStr>;
+    let mut rarity = None::<Ident>;
+    let mut price = None::<LitInt>;
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("joker")) {
+        attr.parse_nested_meta(|meta| {
+            let value = meta.value()?;
+
+            if meta.path.is_ident("id") {
+                id = Some(value.parse()?);
+            } else if meta.path.is_ident("name") {
+                name = Some(value.parse()?);
+            } else if meta.path.is_ident("rarity") {
+                rarity = Some(value.parse()?);
+            } else if meta.path.is_ident("price") {
+                price = Some(value.parse()?);
+            } else {
+                return Err(meta.error("expected `id`, `name`, `rarity` or `price`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let missing =
+        |key| Error::new_spanned(&input.ident, format!("missing `#[joker({key} = ...)]`"));
+    let id = id.ok_or_else(|| missing("id"))?;
+    let name = name.ok_or_else(|| missing("name"))?;
+    let rarity = rarity.ok_or_else(|| missing("rarity"))?;
+    let price = price.ok_or_else(|| missing("price"))?;
+
+    // a generic joker has no single type to register
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "jokers can't be generic",
+        ));
+    }
+
+    let ident = &input.ident;
+
+    Ok(quote! {
+        impl ::balatro::JokerInfo for #ident {
+            fn id(&self) -> &'static str {
+                #id
+            }
+
+            fn name(&self) -> &'static str {
+                #name
+            }
+
+            fn rarity(&self) -> ::balatro::Rarity {
+                ::balatro::Rarity::#rarity
+            }
+
+            fn price(&self) -> ::balatro::Money {
+                ::balatro::Money(#price)
+            }
+        }
+
+        ::balatro::inventory::submit! {
+            ::balatro::JokerRegistration(::balatro::JokerEntry::of::<#ident>)
+        }
+    })
+}
@@ -1,8 +1,16 @@
 use proc_macro::TokenStream;
-use syn::{ItemEnum, parse_macro_input};
+use syn::{DeriveInput, ItemEnum, parse_macro_input};
 
+mod joker;
 mod variants;
 
+#[proc_macro_derive(JokerInfo, attributes(joker))]
+pub fn joker_info(input: TokenStream) -> TokenStream {
+    joker::joker_info(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(|e| e.into_compile_error())
+        .into()
+}
+
 #[proc_macro_derive(Variants)]
 pub fn variants(input: TokenStream) -> TokenStream {
     variants::variants(parse_macro_input!(input as ItemEnum))
@@ -1,11 +1,96 @@
-use std::num::NonZero;
+use crate::{Score, Stake};
 
+/// Antes start at 1, but vouchers like Hieroglyph can take them to 0 and below.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
-pub struct Ante(NonZero<u8>);
+pub struct Ante(i32);
+
+impl Ante {
+    /// The ante that wins the run, unless a challenge says otherwise.
+    pub const WIN: Self = Self(8);
+
+    pub const fn new(ante: i32) -> Self {
+        Self(ante)
+    }
+
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    pub const fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+
+    pub const fn prev(self) -> Self {
+        Self(self.0 - 1)
+    }
+
+    /// The chips needed to beat a small blind, which other blinds are multiples of.
+    pub fn base_chips(self, stake: Stake) -> Score {
+        let amounts: [u64; 8] = match stake.scaling() {
+            1 => [300, 800, 2000, 5000, 11000, 20000, 35000, 50000],
+            2 => [300, 900, 2600, 8000, 20000, 36000, 60000, 100000],
+            _ => [300, 1000, 3200, 9000, 25000, 60000, 110000, 200000],
+        };
+
+        let Ok(ante @ 1..) = usize::try_from(self.get()) else {
+            return Score::from(100);
+        };
+
+        if ante <= amounts.len() {
+            return Score::from(amounts[ante - 1]);
+        }
+
+        // past the table, the game grows the last amount in doubles and keeps only the
+        // first two significant digits
+        let (a, b, k) = (amounts[7] as f64, 1.6, 0.75);
+        let c = (ante - amounts.len()) as f64;
+        let d = 1.0 + 0.2 * c;
+
+        let amount = (a * (b + (k * c).powf(d)).powf(c)).floor();
+
+        if !amount.is_finite() {
+            return Score::INFINITY;
+        }
+
+        let unit = 10f64.powf((amount.log10() - 1.0).floor());
+        Score::from_f64(amount - amount % unit)
+    }
+}
 
 impl Default for Ante {
     fn default() -> Self {
-        Self(unsafe { NonZero::new_unchecked(1) })
+        Self(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ante(ante: i32) -> Ante {
+        Ante::new(ante)
+    }
+
+    #[test]
+    fn base_chips() {
+        assert_eq!(ante(1).base_chips(Stake::White), Score::from(300));
+        assert_eq!(ante(8).base_chips(Stake::Red), Score::from(50000));
+        assert_eq!(ante(4).base_chips(Stake::Green), Score::from(8000));
+        assert_eq!(ante(8).base_chips(Stake::Gold), Score::from(200000));
+    }
+
+    #[test]
+    fn below_one() {
+        assert_eq!(ante(0).base_chips(Stake::White), Score::from(100));
+        assert_eq!(ante(-2).base_chips(Stake::Purple), Score::from(100));
+    }
+
+    #[test]
+    fn endless_base_chips() {
+        assert_eq!(ante(9).base_chips(Stake::White), Score::from(110000));
+        assert_eq!(ante(10).base_chips(Stake::White), Score::from(560000));
+        assert_eq!(ante(12).base_chips(Stake::White), Score::from(300000000));
+        assert!(ante(200).base_chips(Stake::White).is_infinite());
     }
 }
@@ -1,23 +1,32 @@
-use crate::Money;
+use crate::{Ante, Money, Score, Stake};
 use enum_assoc::Assoc;
 
 #[derive(Assoc, Debug, Copy, Clone, PartialEq, Eq)]
 #[func(pub const fn reward(self) -> Money)]
-#[func(const fn score_mult(self) -> usize)]
+#[func(pub const fn score_mult(self) -> f64)]
 pub enum Blind {
-    #[assoc(reward = Money(3), score_mult = 2)]
+    #[assoc(reward = Money(3), score_mult = 1.0)]
     Small,
-    #[assoc(reward = Money(4), score_mult = 3)]
+    #[assoc(reward = Money(4), score_mult = 1.5)]
     Big,
-    #[assoc(reward = Money(5), score_mult = 5)]
+    #[assoc(reward = Money(5), score_mult = _0.score_mult())]
     Boss(Boss),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+impl Blind {
+    /// The score needed to beat this blind.
+    pub fn target(self, ante: Ante, stake: Stake) -> Score {
+        ante.base_chips(stake) * self.score_mult()
+    }
+}
+
+#[derive(Assoc, Debug, Copy, Clone, PartialEq, Eq)]
+#[func(pub const fn score_mult(self) -> f64 { 2.0 })]
 pub enum Boss {
     Hook,
     Ox,
     House,
+    #[assoc(score_mult = 4.0)]
     Wall,
     Wheel,
     Arm,
@@ -33,9 +42,45 @@ pub enum Boss {
     Plant,
     Serpent,
     Pillar,
+    #[assoc(score_mult = 1.0)]
     Needle,
     Head,
     Tooth,
     Flint,
     Mark,
+    AmberAcorn,
+    VerdantLeaf,
+    #[assoc(score_mult = 6.0)]
+    VioletVessel,
+    CrimsonHeart,
+    CeruleanBell,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target() {
+        let ante = Ante::new(2);
+
+        assert_eq!(Blind::Small.target(ante, Stake::White), Score::from(800));
+        assert_eq!(Blind::Big.target(ante, Stake::White), Score::from(1200));
+        assert_eq!(
+            Blind::Boss(Boss::Hook).target(ante, Stake::Green),
+            Score::from(1800)
+        );
+        assert_eq!(
+            Blind::Boss(Boss::Wall).target(ante, Stake::White),
+            Score::from(3200)
+        );
+        assert_eq!(
+            Blind::Boss(Boss::VioletVessel).target(ante, Stake::White),
+            Score::from(4800)
+        );
+        assert_eq!(
+            Blind::Boss(Boss::Needle).target(ante, Stake::White),
+            Score::from(800)
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Edition, HasEdition, Rank, Suit};
+use crate::{Chips, Edition, HasEdition, Rank, Suit};
 use std::convert::Infallible;
 
 #[derive(Debug, Clone)]
@@ -33,6 +33,27 @@ impl Card {
     pub fn builder(rank: Rank, suit: Suit) -> CardBuilder {
         CardBuilder(Self::new(rank, suit))
     }
+
+    pub fn is_wild(&self) -> bool {
+        self.enhancement == Some(Enhancement::Wild)
+    }
+
+    /// Stone cards have no rank or suit, so `rank` and `suit` are meaningless for them.
+    pub fn is_stone(&self) -> bool {
+        self.enhancement == Some(Enhancement::Stone)
+    }
+
+    pub fn is_face(&self) -> bool {
+        !self.is_stone() && self.rank.is_face()
+    }
+
+    pub fn chips(&self) -> Chips {
+        if self.is_stone() {
+            Chips(50)
+        } else {
+            Chips(self.rank.score())
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -78,3 +99,19 @@ pub enum Seal {
     Blue,
     Purple,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chips() {
+        let card = Card::new(Rank::King, Suit::Heart);
+        let stone = Card::builder(Rank::King, Suit::Heart)
+            .enhancement(Enhancement::Stone)
+            .build();
+
+        assert_eq!(card.chips(), Chips(10));
+        assert_eq!(stone.chips(), Chips(50));
+    }
+}
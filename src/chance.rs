@@ -0,0 +1,130 @@
+use crate::{Joker, JokerSlateExt, OopsAllSixesJoker, Slate};
+use rand::Rng;
+use std::fmt;
+
+/// A "`numerator` in `denominator`" chance, as listed on cards.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Chance {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl Chance {
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
+        assert!(denominator > 0, "chance with a zero denominator");
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    pub const fn one_in(denominator: u64) -> Self {
+        Self::new(1, denominator)
+    }
+
+    pub const fn numerator(self) -> u64 {
+        self.numerator
+    }
+
+    pub const fn denominator(self) -> u64 {
+        self.denominator
+    }
+
+    /// Capped at 1, since the numerator can be scaled past the denominator.
+    pub fn probability(self) -> f64 {
+        (self.numerator as f64 / self.denominator as f64).min(1.0)
+    }
+
+    pub const fn is_certain(self) -> bool {
+        self.numerator >= self.denominator
+    }
+
+    pub fn roll(self, rng: &mut impl Rng) -> bool {
+        rng.random::<f64>() < self.probability()
+    }
+}
+
+impl fmt::Display for Chance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in {}", self.numerator, self.denominator)
+    }
+}
+
+/// How the jokers change every listed chance. Effects should roll through this rather
+/// than using their listed [`Chance`] directly.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Probabilities {
+    /// Multiplies every numerator.
+    pub factor: u64,
+}
+
+impl Probabilities {
+    pub fn new(jokers: &Slate<Joker>) -> Self {
+        let oops = jokers
+            .active()
+            .filter(|joker| joker.is::<OopsAllSixesJoker>())
+            .count();
+
+        Self { factor: 1 << oops }
+    }
+
+    pub const fn scale(self, chance: Chance) -> Chance {
+        Chance::new(chance.numerator * self.factor, chance.denominator)
+    }
+}
+
+impl Default for Probabilities {
+    fn default() -> Self {
+        Self { factor: 1 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PERISHABLE_ROUNDS, Sticker};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn probability() {
+        assert_eq!(Chance::one_in(4).probability(), 0.25);
+        assert_eq!(Chance::new(3, 2).probability(), 1.0);
+        assert_eq!(Chance::new(2, 3).to_string(), "2 in 3");
+    }
+
+    #[test]
+    fn certain_always_rolls() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert!((0..100).all(|_| Chance::new(2, 2).roll(&mut rng)));
+        assert!((0..100).all(|_| !Chance::new(0, 2).roll(&mut rng)));
+    }
+
+    #[test]
+    fn oops_all_sixes_stack() {
+        let mut jokers = Slate::new(5);
+        assert_eq!(Probabilities::new(&jokers).factor, 1);
+
+        jokers.push(Joker::new(OopsAllSixesJoker)).unwrap();
+        jokers.push(Joker::new(OopsAllSixesJoker)).unwrap();
+
+        let chance = Probabilities::new(&jokers).scale(Chance::one_in(6));
+        assert_eq!(chance, Chance::new(4, 6));
+    }
+
+    #[test]
+    fn debuffed_oops_all_sixes() {
+        let mut jokers = Slate::new(5);
+        let mut oops = Joker::builder(OopsAllSixesJoker)
+            .sticker(Sticker::Perishable)
+            .build();
+
+        for _ in 0..PERISHABLE_ROUNDS {
+            oops.end_round();
+        }
+
+        jokers.push(oops).unwrap();
+
+        assert_eq!(Probabilities::new(&jokers).factor, 1);
+    }
+}
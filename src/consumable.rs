@@ -0,0 +1,47 @@
+use crate::{Edition, HasEdition, Money, Planet, Tarot};
+use std::convert::Infallible;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Consumable {
+    pub kind: ConsumableKind,
+    pub edition: Option<Edition<Self>>,
+    /// Added to the sell value, as Gift Card does.
+    pub extra_value: Money,
+}
+
+impl HasEdition for Consumable {
+    type Scoring = Infallible;
+    type Negative = ();
+
+    fn edition(&self) -> Option<Edition<Self>> {
+        self.edition
+    }
+}
+
+impl Consumable {
+    pub const fn new(kind: ConsumableKind) -> Self {
+        Self {
+            kind,
+            edition: None,
+            extra_value: Money::ZERO,
+        }
+    }
+}
+
+impl From<Planet> for Consumable {
+    fn from(planet: Planet) -> Self {
+        Self::new(ConsumableKind::Planet(planet))
+    }
+}
+
+impl From<Tarot> for Consumable {
+    fn from(tarot: Tarot) -> Self {
+        Self::new(ConsumableKind::Tarot(tarot))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConsumableKind {
+    Planet(Planet),
+    Tarot(Tarot),
+}
@@ -1,5 +1,8 @@
 use super::*;
-use im_rc::Vector;
+use im::Vector;
+
+#[cfg(not(feature = "sync"))]
+use im_rc as im;
 
 #[derive(Debug, Clone)]
 pub struct Slate<T: Clone> {
@@ -44,6 +47,19 @@ where
         self.base_cap
     }
 
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.vector.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.vector.get_mut(index)
+    }
+
+    /// Negatives bring their own slot, so they fit even when the slate is full.
+    pub fn fits(&self, item: &T) -> bool {
+        item.is_negative() || !self.is_full()
+    }
+
     pub fn push(&mut self, item: T) -> Result<(), T> {
         if item.is_negative() {
             self.vector.push_back(item);
@@ -65,11 +81,11 @@ where
         item
     }
 
-    pub fn iter(&self) -> SlateIter<T> {
+    pub fn iter(&self) -> SlateIter<'_, T> {
         self.into_iter()
     }
 
-    pub fn iter_mut(&mut self) -> SlateIterMut<T> {
+    pub fn iter_mut(&mut self) -> SlateIterMut<'_, T> {
         self.into_iter()
     }
 }
@@ -101,7 +117,7 @@ impl<T: Clone> IntoIterator for Slate<T> {
     }
 }
 
-pub struct SlateIter<'a, T>(im_rc::vector::Iter<'a, T>);
+pub struct SlateIter<'a, T>(im::vector::Iter<'a, T>);
 
 impl<'a, T: Clone> Iterator for SlateIter<'a, T> {
     type Item = &'a T;
@@ -111,7 +127,7 @@ impl<'a, T: Clone> Iterator for SlateIter<'a, T> {
     }
 }
 
-pub struct SlateIterMut<'a, T>(im_rc::vector::IterMut<'a, T>);
+pub struct SlateIterMut<'a, T>(im::vector::IterMut<'a, T>);
 
 impl<'a, T: Clone> Iterator for SlateIterMut<'a, T> {
     type Item = &'a mut T;
@@ -121,7 +137,7 @@ impl<'a, T: Clone> Iterator for SlateIterMut<'a, T> {
     }
 }
 
-pub struct SlateIntoIter<T>(im_rc::vector::ConsumingIter<T>);
+pub struct SlateIntoIter<T>(im::vector::ConsumingIter<T>);
 
 impl<T: Clone> Iterator for SlateIntoIter<T> {
     type Item = T;
@@ -0,0 +1,486 @@
+use super::{HandRules, HandType};
+use crate::{Card, Rank, Suit};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedHand {
+    hand_type: HandType,
+    scoring: Vec<usize>,
+}
+
+impl DetectedHand {
+    pub const fn hand_type(&self) -> HandType {
+        self.hand_type
+    }
+
+    /// Indices into the played cards of the cards that score, in played order.
+    pub fn scoring(&self) -> &[usize] {
+        &self.scoring
+    }
+
+    pub fn is_scoring(&self, index: usize) -> bool {
+        self.scoring.binary_search(&index).is_ok()
+    }
+
+    pub fn scoring_cards<'a>(&'a self, cards: &'a [Card]) -> impl Iterator<Item = &'a Card> {
+        self.scoring.iter().map(|&index| &cards[index])
+    }
+}
+
+impl HandType {
+    pub fn detect(cards: &[Card]) -> DetectedHand {
+        Self::detect_with(cards, HandRules::default())
+    }
+
+    pub fn detect_with(cards: &[Card], rules: HandRules) -> DetectedHand {
+        Parts::new(cards, rules).detect(cards)
+    }
+}
+
+const HAND_SIZE: usize = 5;
+
+#[derive(Debug)]
+struct Parts {
+    same: Vec<Vec<usize>>,
+    flush: Option<Vec<usize>>,
+    straight: Option<Vec<usize>>,
+    highest: Option<usize>,
+}
+
+impl Parts {
+    fn new(cards: &[Card], rules: HandRules) -> Self {
+        Self {
+            same: same(cards),
+            flush: flush(cards, rules),
+            straight: straight(cards, rules),
+            highest: highest(cards),
+        }
+    }
+
+    fn same(&self, count: usize) -> impl Iterator<Item = &[usize]> {
+        self.same
+            .iter()
+            .filter(move |group| group.len() == count)
+            .map(Vec::as_slice)
+    }
+
+    fn detect(&self, cards: &[Card]) -> DetectedHand {
+        let five = self.same(5).next();
+        let four = self.same(4).next();
+        let three = self.same(3).next();
+        let pairs = self.same(2).collect::<Vec<_>>();
+        let flush = self.flush.as_deref();
+        let straight = self.straight.as_deref();
+
+        let (hand_type, parts): (_, Vec<&[usize]>) =
+            if let (Some(five), Some(flush)) = (five, flush) {
+                (HandType::FlushFive, vec![five, flush])
+            } else if let (Some(three), [pair, ..], Some(flush)) = (three, &pairs[..], flush) {
+                (HandType::FlushHouse, vec![three, pair, flush])
+            } else if let Some(five) = five {
+                (HandType::FiveOfAKind, vec![five])
+            } else if let (Some(straight), Some(flush)) = (straight, flush) {
+                (HandType::StraightFlush, vec![straight, flush])
+            } else if let Some(four) = four {
+                (HandType::FourOfAKind, vec![four])
+            } else if let (Some(three), [pair, ..]) = (three, &pairs[..]) {
+                (HandType::FullHouse, vec![three, pair])
+            } else if let Some(flush) = flush {
+                (HandType::Flush, vec![flush])
+            } else if let Some(straight) = straight {
+                (HandType::Straight, vec![straight])
+            } else if let Some(three) = three {
+                (HandType::ThreeOfAKind, vec![three])
+            } else if let [a, b, ..] = &pairs[..] {
+                (HandType::TwoPair, vec![a, b])
+            } else if let [pair] = &pairs[..] {
+                (HandType::Pair, vec![pair])
+            } else {
+                (HandType::HighCard, vec![self.highest.as_slice()])
+            };
+
+        let mut scoring = parts.concat();
+
+        // Stone cards never make up part of a hand, but they always score.
+        scoring.extend((0..cards.len()).filter(|&index| cards[index].is_stone()));
+        scoring.sort_unstable();
+        scoring.dedup();
+
+        DetectedHand { hand_type, scoring }
+    }
+}
+
+fn same(cards: &[Card]) -> Vec<Vec<usize>> {
+    let mut groups = Vec::<(Rank, Vec<usize>)>::new();
+
+    for (index, rank) in ranked(cards) {
+        match groups.iter_mut().find(|(r, _)| *r == rank) {
+            Some((_, group)) => group.push(index),
+            None => groups.push((rank, vec![index])),
+        }
+    }
+
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+fn flush(cards: &[Card], rules: HandRules) -> Option<Vec<usize>> {
+    if !fits(cards, rules.flush_len()) {
+        return None;
+    }
+
+    [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade]
+        .into_iter()
+        .map(|suit| {
+            (0..cards.len())
+                .filter(|&index| rules.is_suit(&cards[index], suit))
+                .collect::<Vec<_>>()
+        })
+        .find(|flush| flush.len() >= rules.flush_len())
+}
+
+fn fits(cards: &[Card], required: usize) -> bool {
+    (required..=HAND_SIZE).contains(&cards.len())
+}
+
+fn straight(cards: &[Card], rules: HandRules) -> Option<Vec<usize>> {
+    if !fits(cards, rules.straight_len()) {
+        return None;
+    }
+
+    // Aces are both the lowest and highest rank, so they take up two slots.
+    let mut present = [false; Rank::VARIANT_COUNT + 1];
+
+    for (_, rank) in ranked(cards) {
+        for slot in slots(rank) {
+            present[slot] = true;
+        }
+    }
+
+    // Runs are tracked as the slots they span along with how many ranks are present
+    // in them, since the span can be longer than the run when ranks are skipped.
+    let mut best = (0..0, 0);
+    let mut run = (0..0, 0);
+
+    for (slot, &present) in present.iter().enumerate() {
+        if !present {
+            continue;
+        }
+
+        if run.1 == 0 || slot >= run.0.end + rules.straight_gap() {
+            run = (slot..slot + 1, 1);
+        } else {
+            run = (run.0.start..slot + 1, run.1 + 1);
+        }
+
+        if run.1 > best.1 {
+            best = run.clone();
+        }
+    }
+
+    let (best, ranks) = best;
+
+    if ranks < rules.straight_len() {
+        return None;
+    }
+
+    let straight = ranked(cards)
+        .filter(|&(_, rank)| slots(rank).any(|slot| best.contains(&slot)))
+        .map(|(index, _)| index)
+        .collect();
+
+    Some(straight)
+}
+
+fn slots(rank: Rank) -> impl Iterator<Item = usize> {
+    let high = rank as usize + 1;
+    let low = (rank == Rank::Ace).then_some(0);
+
+    low.into_iter().chain([high])
+}
+
+fn highest(cards: &[Card]) -> Option<usize> {
+    ranked(cards)
+        .max_by_key(|&(_, rank)| rank)
+        .map(|(index, _)| index)
+}
+
+fn ranked(cards: &[Card]) -> impl Iterator<Item = (usize, Rank)> {
+    cards
+        .iter()
+        .enumerate()
+        .filter(|(_, card)| !card.is_stone())
+        .map(|(index, card)| (index, card.rank))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Enhancement;
+    use HandType::*;
+    use Rank::*;
+    use Suit::*;
+
+    macro_rules! cards {
+        ($($rank:ident $suit:ident),*$(,)?) => {
+            [$(Card::new($rank, $suit)),*]
+        };
+    }
+
+    macro_rules! assert_detects {
+        ([$($cards:tt)*] => $hand_type:ident $scoring:tt) => {
+            assert_detects!(HandRules::default(), [$($cards)*] => $hand_type $scoring)
+        };
+        ($rules:expr, [$($cards:tt)*] => $hand_type:ident $scoring:tt) => {
+            let hand = HandType::detect_with(&cards![$($cards)*], $rules);
+
+            assert_eq!(hand.hand_type(), $hand_type);
+            assert_eq!(hand.scoring(), &$scoring);
+        };
+    }
+
+    #[test]
+    fn empty() {
+        assert_detects!([] => HighCard []);
+    }
+
+    #[test]
+    fn high_card() {
+        assert_detects!([Two Club, King Heart, Nine Spade] => HighCard [1]);
+    }
+
+    #[test]
+    fn pair() {
+        assert_detects!([Two Club, Nine Heart, Two Spade, Ace Club] => Pair [0, 2]);
+    }
+
+    #[test]
+    fn two_pair() {
+        assert_detects!(
+            [Two Club, Nine Heart, Two Spade, Nine Club, Ace Club] => TwoPair [0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn three_of_a_kind() {
+        assert_detects!(
+            [Two Club, Two Heart, Ace Club, Two Spade] => ThreeOfAKind [0, 1, 3]
+        );
+    }
+
+    #[test]
+    fn straight() {
+        assert_detects!(
+            [Six Club, Two Heart, Four Club, Three Spade, Five Diamond] => Straight [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn straight_ace_low() {
+        assert_detects!(
+            [Ace Club, Two Heart, Three Club, Four Spade, Five Diamond] => Straight [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn straight_ace_high() {
+        assert_detects!(
+            [Ace Club, King Heart, Queen Club, Jack Spade, Ten Diamond] => Straight [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn straight_does_not_wrap() {
+        assert_detects!(
+            [Queen Club, King Heart, Ace Club, Two Spade, Three Diamond] => HighCard [2]
+        );
+    }
+
+    #[test]
+    fn straight_needs_five_cards() {
+        assert_detects!([Two Club, Three Heart, Four Club, Five Spade] => HighCard [3]);
+    }
+
+    #[test]
+    fn flush() {
+        assert_detects!(
+            [Two Heart, Nine Heart, King Heart, Four Heart, Six Heart] => Flush [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn flush_needs_five_cards() {
+        assert_detects!([Two Heart, Nine Heart, King Heart, Four Heart] => HighCard [2]);
+    }
+
+    #[test]
+    fn full_house() {
+        assert_detects!(
+            [Two Heart, Nine Club, Two Club, Nine Heart, Two Spade] => FullHouse [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn four_of_a_kind() {
+        assert_detects!(
+            [Two Heart, Two Club, Nine Heart, Two Diamond, Two Spade] => FourOfAKind [0, 1, 3, 4]
+        );
+    }
+
+    #[test]
+    fn straight_flush() {
+        assert_detects!(
+            [Six Club, Two Club, Four Club, Three Club, Five Club] => StraightFlush [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn five_of_a_kind() {
+        assert_detects!(
+            [Ace Heart, Ace Club, Ace Heart, Ace Diamond, Ace Spade] => FiveOfAKind [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn flush_house() {
+        assert_detects!(
+            [Two Heart, Nine Heart, Two Heart, Nine Heart, Two Heart] => FlushHouse [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn flush_five() {
+        assert_detects!(
+            [Ace Spade, Ace Spade, Ace Spade, Ace Spade, Ace Spade] => FlushFive [0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn four_fingers() {
+        let rules = HandRules {
+            four_fingers: true,
+            ..Default::default()
+        };
+
+        assert_detects!(
+            rules,
+            [Two Heart, Nine Heart, King Heart, Four Heart] => Flush [0, 1, 2, 3]
+        );
+        assert_detects!(
+            rules,
+            [Two Heart, Nine Club, King Heart, Four Heart, Six Heart] => Flush [0, 2, 3, 4]
+        );
+        assert_detects!(
+            rules,
+            [Two Club, Three Heart, Four Club, Five Spade] => Straight [0, 1, 2, 3]
+        );
+        assert_detects!(
+            rules,
+            [Two Club, Three Heart, Four Club, Five Spade, King Spade] => Straight [0, 1, 2, 3]
+        );
+        assert_detects!(
+            rules,
+            [Two Club, Three Club, Four Club, Five Club, King Spade] => StraightFlush [0, 1, 2, 3]
+        );
+        assert_detects!(rules, [Two Club, Three Club, Four Club] => HighCard [2]);
+    }
+
+    #[test]
+    fn shortcut() {
+        let rules = HandRules {
+            shortcut: true,
+            ..Default::default()
+        };
+
+        assert_detects!(
+            rules,
+            [Two Club, Four Heart, Six Club, Eight Spade, Ten Club] => Straight [0, 1, 2, 3, 4]
+        );
+        assert_detects!(
+            rules,
+            [Ace Club, Three Heart, Four Club, Six Spade, Seven Club] => Straight [0, 1, 2, 3, 4]
+        );
+        assert_detects!(
+            rules,
+            [Two Club, Three Heart, Four Club, Five Spade, Eight Club] => HighCard [4]
+        );
+    }
+
+    #[test]
+    fn smeared() {
+        let rules = HandRules {
+            smeared: true,
+            ..Default::default()
+        };
+
+        assert_detects!(
+            rules,
+            [Two Heart, Nine Diamond, King Heart, Four Diamond, Six Heart] => Flush [0, 1, 2, 3, 4]
+        );
+        assert_detects!(
+            rules,
+            [Two Spade, Nine Club, King Heart, Four Club, Six Spade] => HighCard [2]
+        );
+    }
+
+    #[test]
+    fn wild_counts_as_every_suit() {
+        let mut cards = cards![Two Heart, Nine Heart, King Spade, Four Heart, Six Heart];
+        cards[2].enhancement = Some(Enhancement::Wild);
+
+        let hand = HandType::detect(&cards);
+
+        assert_eq!(hand.hand_type(), Flush);
+        assert_eq!(hand.scoring(), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn stone_is_not_part_of_hand_but_scores() {
+        let mut cards = cards![Two Heart, Nine Club, Two Spade, Two Club];
+        cards[3].enhancement = Some(Enhancement::Stone);
+
+        let hand = HandType::detect(&cards);
+
+        assert_eq!(hand.hand_type(), Pair);
+        assert_eq!(hand.scoring(), [0, 2, 3]);
+    }
+
+    #[test]
+    fn stone_breaks_flush() {
+        let mut cards = cards![Two Heart, Nine Heart, King Heart, Four Heart, Six Heart];
+        cards[4].enhancement = Some(Enhancement::Stone);
+
+        let hand = HandType::detect(&cards);
+
+        assert_eq!(hand.hand_type(), HighCard);
+        assert_eq!(hand.scoring(), [2, 4]);
+    }
+
+    #[test]
+    fn only_stones() {
+        let mut cards = cards![Ace Heart, Ace Spade];
+        cards[0].enhancement = Some(Enhancement::Stone);
+        cards[1].enhancement = Some(Enhancement::Stone);
+
+        let hand = HandType::detect(&cards);
+
+        assert_eq!(hand.hand_type(), HighCard);
+        assert_eq!(hand.scoring(), [0, 1]);
+    }
+
+    #[test]
+    fn is_scoring() {
+        let hand = HandType::detect(&cards![Two Club, Nine Heart, Two Spade]);
+
+        assert!(hand.is_scoring(0));
+        assert!(!hand.is_scoring(1));
+        assert!(hand.is_scoring(2));
+    }
+
+    #[test]
+    fn scoring_cards() {
+        let cards = cards![Two Club, Nine Heart, Two Spade];
+        let hand = HandType::detect(&cards);
+        let suits = hand.scoring_cards(&cards).map(|card| card.suit);
+
+        assert_eq!(suits.collect::<Vec<_>>(), [Club, Spade]);
+    }
+}
@@ -1,5 +1,9 @@
+mod detect;
+mod rules;
 mod state;
 mod r#type;
 
+pub use detect::*;
+pub use rules::*;
 pub use state::*;
 pub use r#type::*;
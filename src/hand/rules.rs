@@ -0,0 +1,138 @@
+use crate::{
+    Card, FourFingersJoker, Joker, JokerSlateExt, ShortcutJoker, Slate, SmearedJoker, Suit,
+};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct HandRules {
+    /// Flushes and straights can be made with four cards.
+    pub four_fingers: bool,
+    /// Straights can skip a single rank between each card.
+    pub shortcut: bool,
+    /// Suits of the same colour count as the same suit.
+    pub smeared: bool,
+}
+
+impl HandRules {
+    pub fn new(jokers: &Slate<Joker>) -> Self {
+        let has = |is: fn(&Joker) -> bool| jokers.active().any(is);
+
+        Self {
+            four_fingers: has(Joker::is::<FourFingersJoker>),
+            shortcut: has(Joker::is::<ShortcutJoker>),
+            smeared: has(Joker::is::<SmearedJoker>),
+        }
+    }
+
+    pub const fn flush_len(self) -> usize {
+        if self.four_fingers { 4 } else { 5 }
+    }
+
+    pub const fn straight_len(self) -> usize {
+        self.flush_len()
+    }
+
+    pub const fn straight_gap(self) -> usize {
+        if self.shortcut { 2 } else { 1 }
+    }
+
+    /// Whether the card counts as the given suit, for flushes and suit-conditional
+    /// effects alike. Wild cards are every suit and Stone cards are no suit.
+    pub fn is_suit(self, card: &Card, suit: Suit) -> bool {
+        if card.is_stone() {
+            false
+        } else if card.is_wild() {
+            true
+        } else {
+            self.same_suit(card.suit, suit)
+        }
+    }
+
+    pub fn same_suit(self, a: Suit, b: Suit) -> bool {
+        if self.smeared {
+            a.family() == b.family()
+        } else {
+            a == b
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Enhancement, PERISHABLE_ROUNDS, Rank, Sticker};
+
+    #[test]
+    fn no_jokers() {
+        let jokers = Slate::<Joker>::new(5);
+
+        assert_eq!(HandRules::new(&jokers), HandRules::default());
+    }
+
+    #[test]
+    fn from_jokers() {
+        let mut jokers = Slate::<Joker>::new(5);
+
+        jokers.push(Joker::new(FourFingersJoker)).unwrap();
+        jokers.push(Joker::new(SmearedJoker)).unwrap();
+
+        let rules = HandRules::new(&jokers);
+
+        assert!(rules.four_fingers);
+        assert!(!rules.shortcut);
+        assert!(rules.smeared);
+    }
+
+    #[test]
+    fn debuffed_jokers() {
+        let mut jokers = Slate::<Joker>::new(5);
+        let mut four_fingers = Joker::builder(FourFingersJoker)
+            .sticker(Sticker::Perishable)
+            .build();
+
+        for _ in 0..PERISHABLE_ROUNDS {
+            four_fingers.end_round();
+        }
+
+        jokers.push(four_fingers).unwrap();
+
+        assert!(!HandRules::new(&jokers).four_fingers);
+    }
+
+    #[test]
+    fn same_suit() {
+        let rules = HandRules::default();
+
+        assert!(rules.same_suit(Suit::Heart, Suit::Heart));
+        assert!(!rules.same_suit(Suit::Heart, Suit::Diamond));
+
+        let rules = HandRules {
+            smeared: true,
+            ..rules
+        };
+
+        assert!(rules.same_suit(Suit::Heart, Suit::Diamond));
+        assert!(rules.same_suit(Suit::Spade, Suit::Club));
+        assert!(!rules.same_suit(Suit::Spade, Suit::Heart));
+    }
+
+    #[test]
+    fn is_suit() {
+        let rules = HandRules::default();
+        let card = Card::new(Rank::Ace, Suit::Heart);
+        let wild = Card::builder(Rank::Ace, Suit::Heart)
+            .enhancement(Enhancement::Wild)
+            .build();
+        let stone = Card::builder(Rank::Ace, Suit::Heart)
+            .enhancement(Enhancement::Stone)
+            .build();
+
+        assert!(rules.is_suit(&card, Suit::Heart));
+        assert!(!rules.is_suit(&card, Suit::Spade));
+
+        assert!(rules.is_suit(&wild, Suit::Heart));
+        assert!(rules.is_suit(&wild, Suit::Spade));
+
+        assert!(!rules.is_suit(&stone, Suit::Heart));
+        assert!(!rules.is_suit(&stone, Suit::Spade));
+    }
+}
@@ -179,7 +179,7 @@ mod tests {
         ($states:ident, { $($hand_type:ident => ($chips:literal, $mult:literal ))*$(,)? }) => {
             for hand_type in HandType::variants() {
                 let expected = match hand_type {
-                    $($hand_type => { (Chips($chips), Mult($mult)) })*
+                    $($hand_type => { (Chips($chips), Mult(f64::from($mult))) })*
                     _ => hand_type.base_score(),
                 };
 
@@ -7,63 +7,63 @@ use enum_assoc::Assoc;
 #[func(pub const fn addl_score_per_level(self) -> (Chips, Mult))]
 pub enum HandType {
     #[assoc(
-        base_score = (Chips(5), Mult(1)),
-        addl_score_per_level = (Chips(10), Mult(1)),
+        base_score = (Chips(5), Mult(1.0)),
+        addl_score_per_level = (Chips(10), Mult(1.0)),
     )]
     HighCard,
     #[assoc(
-        base_score = (Chips(10), Mult(2)),
-        addl_score_per_level = (Chips(10), Mult(2)),
+        base_score = (Chips(10), Mult(2.0)),
+        addl_score_per_level = (Chips(10), Mult(2.0)),
     )]
     Pair,
     #[assoc(
-        base_score = (Chips(20), Mult(2)),
-        addl_score_per_level = (Chips(20), Mult(1)),
+        base_score = (Chips(20), Mult(2.0)),
+        addl_score_per_level = (Chips(20), Mult(1.0)),
     )]
     TwoPair,
     #[assoc(
-        base_score = (Chips(30), Mult(3)),
-        addl_score_per_level = (Chips(20), Mult(2)),
+        base_score = (Chips(30), Mult(3.0)),
+        addl_score_per_level = (Chips(20), Mult(2.0)),
     )]
     ThreeOfAKind,
     #[assoc(
-        base_score = (Chips(30), Mult(4)),
-        addl_score_per_level = (Chips(30), Mult(3)),
+        base_score = (Chips(30), Mult(4.0)),
+        addl_score_per_level = (Chips(30), Mult(3.0)),
     )]
     Straight,
     #[assoc(
-        base_score = (Chips(35), Mult(4)),
-        addl_score_per_level = (Chips(15), Mult(2)),
+        base_score = (Chips(35), Mult(4.0)),
+        addl_score_per_level = (Chips(15), Mult(2.0)),
     )]
     Flush,
     #[assoc(
-        base_score = (Chips(40), Mult(4)),
-        addl_score_per_level = (Chips(25), Mult(2)),
+        base_score = (Chips(40), Mult(4.0)),
+        addl_score_per_level = (Chips(25), Mult(2.0)),
     )]
     FullHouse,
     #[assoc(
-        base_score = (Chips(60), Mult(7)),
-        addl_score_per_level = (Chips(30), Mult(3)),
+        base_score = (Chips(60), Mult(7.0)),
+        addl_score_per_level = (Chips(30), Mult(3.0)),
     )]
     FourOfAKind,
     #[assoc(
-        base_score = (Chips(100), Mult(8)),
-        addl_score_per_level = (Chips(40), Mult(4)),
+        base_score = (Chips(100), Mult(8.0)),
+        addl_score_per_level = (Chips(40), Mult(4.0)),
     )]
     StraightFlush,
     #[assoc(
-        base_score = (Chips(120), Mult(12)),
-        addl_score_per_level = (Chips(35), Mult(3)),
+        base_score = (Chips(120), Mult(12.0)),
+        addl_score_per_level = (Chips(35), Mult(3.0)),
     )]
     FiveOfAKind,
     #[assoc(
-        base_score = (Chips(140), Mult(14)),
-        addl_score_per_level = (Chips(40), Mult(4)),
+        base_score = (Chips(140), Mult(14.0)),
+        addl_score_per_level = (Chips(40), Mult(4.0)),
     )]
     FlushHouse,
     #[assoc(
-        base_score = (Chips(160), Mult(16)),
-        addl_score_per_level = (Chips(50), Mult(3)),
+        base_score = (Chips(160), Mult(16.0)),
+        addl_score_per_level = (Chips(50), Mult(3.0)),
     )]
     FlushFive,
 }
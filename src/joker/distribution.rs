@@ -0,0 +1,149 @@
+use crate::Score;
+use thiserror::Error;
+
+/// Every score a hand can reach, with the probability of reaching it.
+#[derive(Debug, Clone)]
+pub struct ScoreDistribution {
+    /// Sorted by score, with no score listed twice.
+    outcomes: Vec<(Score, f64)>,
+}
+
+impl ScoreDistribution {
+    pub(crate) fn new(mut outcomes: Vec<(Score, f64)>) -> Self {
+        outcomes.sort_by_key(|&(score, _)| score);
+        outcomes.dedup_by(|(score, p), (prev_score, prev_p)| {
+            let same = score == prev_score;
+            if same {
+                *prev_p += *p;
+            }
+            same
+        });
+
+        Self { outcomes }
+    }
+
+    pub fn outcomes(&self) -> &[(Score, f64)] {
+        &self.outcomes
+    }
+
+    pub fn min(&self) -> Score {
+        self.outcomes[0].0
+    }
+
+    pub fn max(&self) -> Score {
+        self.outcomes[self.outcomes.len() - 1].0
+    }
+
+    pub fn mean(&self) -> Score {
+        self.outcomes
+            .iter()
+            .fold(Score::ZERO, |mean, &(score, p)| mean + score * p)
+    }
+
+    /// The chance of scoring at least `target`, such as a blind's chip requirement.
+    pub fn probability_at_least(&self, target: Score) -> f64 {
+        let outcomes = self.outcomes.iter().filter(|(score, _)| *score >= target);
+        outcomes.map(|(_, p)| p).sum()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
+#[error("hand has more than {limit} possible outcomes")]
+pub struct TooManyOutcomes {
+    pub limit: usize,
+}
+
+/// Where a [`Scorer`](crate::Scorer) gets its random outcomes from.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum Draws {
+    #[default]
+    Random,
+    /// Replays the choices made so far and takes the first option of any new ones.
+    Path(Path),
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Path {
+    choices: Vec<Choice>,
+    next: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Choice {
+    option: usize,
+    weights: Vec<f64>,
+}
+
+impl Path {
+    /// Picks an option at the next choice point, given the weight of each option.
+    pub fn choose(&mut self, weights: impl FnOnce() -> Vec<f64>) -> usize {
+        if self.next == self.choices.len() {
+            self.choices.push(Choice {
+                option: 0,
+                weights: weights(),
+            });
+        }
+
+        self.next += 1;
+        self.choices[self.next - 1].option
+    }
+
+    pub fn probability(&self) -> f64 {
+        let weights = self.choices.iter().map(|c| c.weights[c.option]);
+        weights.product()
+    }
+
+    /// The next path to explore, depth first, or `None` once every path has been.
+    pub fn advance(mut self) -> Option<Self> {
+        while let Some(mut choice) = self.choices.pop() {
+            if choice.option + 1 < choice.weights.len() {
+                choice.option += 1;
+                self.choices.push(choice);
+                self.next = 0;
+
+                return Some(self);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_equal_scores() {
+        let dist = ScoreDistribution::new(vec![
+            (Score::from(20), 0.25),
+            (Score::from(10), 0.5),
+            (Score::from(20), 0.25),
+        ]);
+
+        assert_eq!(
+            dist.outcomes(),
+            [(Score::from(10), 0.5), (Score::from(20), 0.5)]
+        );
+        assert_eq!(dist.mean(), Score::from(15));
+        assert_eq!(dist.probability_at_least(Score::from(11)), 0.5);
+    }
+
+    #[test]
+    fn paths_cover_every_choice() {
+        let mut path = Some(Path::default());
+        let mut seen = Vec::new();
+
+        while let Some(mut current) = path {
+            let a = current.choose(|| vec![0.5, 0.5]);
+            let b = current.choose(|| vec![1.0 / 3.0; 3]);
+
+            seen.push((a, b));
+            path = current.advance();
+        }
+
+        assert_eq!(seen.len(), 6);
+        assert_eq!(seen[0], (0, 0));
+        assert_eq!(seen[5], (1, 2));
+    }
+}
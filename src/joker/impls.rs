@@ -1,9 +1,67 @@
+mod baron;
+mod bloodstone;
+mod blueprint;
+mod bootstraps;
+mod brainstorm;
+mod bull;
+mod business_card;
+mod credit_card;
+mod dusk;
+mod egg;
+mod faceless;
+mod four_fingers;
+mod gift_card;
+mod glass;
+mod greedy;
+mod green;
+mod hack;
+mod hanging_chad;
+mod hologram;
+mod ice_cream;
 mod jimbo;
+mod lucky_cat;
+mod mime;
 mod misprint;
+mod oops_all_sixes;
+mod ride_the_bus;
+mod runner;
+mod seltzer;
+mod shortcut;
+mod smeared;
+mod sock_and_buskin;
 mod stencil;
 
+pub use baron::*;
+pub use bloodstone::*;
+pub use blueprint::*;
+pub use bootstraps::*;
+pub use brainstorm::*;
+pub use bull::*;
+pub use business_card::*;
+pub use credit_card::*;
+pub use dusk::*;
+pub use egg::*;
+pub use faceless::*;
+pub use four_fingers::*;
+pub use gift_card::*;
+pub use glass::*;
+pub use greedy::*;
+pub use green::*;
+pub use hack::*;
+pub use hanging_chad::*;
+pub use hologram::*;
+pub use ice_cream::*;
 pub use jimbo::*;
+pub use lucky_cat::*;
+pub use mime::*;
 pub use misprint::*;
+pub use oops_all_sixes::*;
+pub use ride_the_bus::*;
+pub use runner::*;
+pub use seltzer::*;
+pub use shortcut::*;
+pub use smeared::*;
+pub use sock_and_buskin::*;
 pub use stencil::*;
 
 #[allow(unused)]
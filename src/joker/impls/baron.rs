@@ -0,0 +1,15 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_baron", name = "Baron", rarity = Rare, price = 8)]
+pub struct BaronJoker;
+
+impl JokerKind for BaronJoker {
+    fn on_held(&mut self, scorer: &mut Scorer, index: usize) {
+        let card = &scorer.held()[index];
+
+        if !card.is_stone() && card.rank == Rank::King {
+            scorer.mult *= 1.5;
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_bloodstone", name = "Bloodstone", rarity = Uncommon, price = 7)]
+pub struct BloodstoneJoker;
+
+impl JokerKind for BloodstoneJoker {
+    fn on_scored(&mut self, scorer: &mut Scorer, index: usize) {
+        let card = &scorer.played()[index];
+
+        if scorer.rules().is_suit(card, Suit::Heart) && scorer.roll("bloodstone", Chance::one_in(2))
+        {
+            scorer.mult *= 1.5;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn certain_with_oops() {
+        let jokers = jokers![5: BloodstoneJoker, OopsAllSixesJoker];
+        let played = [Card::new(Two, Heart), Card::new(Two, Spade)];
+        let hands = HandTypeStates::default();
+
+        for seed in 0..8 {
+            let score = Scorer::builder(&played, &[], &hands, &jokers)
+                .rng(RunRng::new(seed))
+                .build()
+                .score();
+
+            assert_eq!(score.mult, Mult(2.0 * 1.5));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_blueprint", name = "Blueprint", rarity = Rare, price = 10)]
+pub struct BlueprintJoker;
+
+impl JokerKind for BlueprintJoker {
+    fn copies(&self, _jokers: &Slate<Joker>, index: usize) -> Option<usize> {
+        Some(index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn copies_right() {
+        let jokers = jokers![5: BlueprintJoker, JimboJoker];
+        let score = score_hand(&[], &[], &HandTypeStates::default(), &jokers);
+
+        assert_eq!(score.mult, Mult(1.0 + 4.0 + 4.0));
+    }
+
+    #[test]
+    fn rightmost_copies_nothing() {
+        let jokers = jokers![5: JimboJoker, BlueprintJoker];
+        let score = score_hand(&[], &[], &HandTypeStates::default(), &jokers);
+
+        assert_eq!(score.mult, Mult(1.0 + 4.0));
+    }
+
+    #[test]
+    fn copies_retriggers() {
+        let jokers = jokers![5: BlueprintJoker, HangingChadJoker];
+        let played = [Card::new(Ace, Heart)];
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &jokers);
+
+        assert_eq!(score.chips, Chips(5 + 11 * 5));
+    }
+
+    #[test]
+    fn sees_but_does_not_change_state() {
+        let jokers = jokers![5: BlueprintJoker, GreenJoker::default()];
+        let mut run = Run::new(jokers, Money(0));
+
+        run.play(&[Card::new(Ace, Heart)], &[]);
+        let score = run.play(&[Card::new(Ace, Heart)], &[]);
+
+        assert_eq!(score.mult, Mult(1.0 + 2.0 + 2.0));
+    }
+
+    #[test]
+    fn incompatible() {
+        let jokers = jokers![5: BlueprintJoker, FourFingersJoker];
+        assert!(jokers.ability(0).is_none());
+    }
+}
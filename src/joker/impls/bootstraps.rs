@@ -0,0 +1,12 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_bootstraps", name = "Bootstraps", rarity = Uncommon, price = 7)]
+pub struct BootstrapsJoker;
+
+impl JokerKind for BootstrapsJoker {
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        let fives = scorer.balance().0.max(0) as u64 / 5;
+        scorer.mult += 2 * fives;
+    }
+}
@@ -0,0 +1,32 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_brainstorm", name = "Brainstorm", rarity = Rare, price = 10)]
+pub struct BrainstormJoker;
+
+impl JokerKind for BrainstormJoker {
+    fn copies(&self, _jokers: &Slate<Joker>, _index: usize) -> Option<usize> {
+        Some(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_leftmost() {
+        let jokers = jokers![5: JimboJoker, StencilJoker, BrainstormJoker];
+        let score = score_hand(&[], &[], &HandTypeStates::default(), &jokers);
+
+        assert_eq!(score.mult, Mult((1.0 + 4.0) * 3.0 + 4.0));
+    }
+
+    #[test]
+    fn loops_copy_nothing() {
+        let jokers = jokers![5: BlueprintJoker, BrainstormJoker];
+
+        assert!(jokers.ability(0).is_none());
+        assert!(jokers.ability(1).is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_bull", name = "Bull", rarity = Uncommon, price = 6)]
+pub struct BullJoker;
+
+impl JokerKind for BullJoker {
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        let dollars = scorer.balance().0.max(0) as u64;
+        scorer.chips += 2 * dollars;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn counts_money_earned_this_hand() {
+        let jokers = jokers![5: BullJoker];
+        let played = [Card::builder(Ace, Heart).seal(Seal::Gold).build()];
+        let hands = HandTypeStates::default();
+
+        let score = |balance| {
+            Scorer::builder(&played, &[], &hands, &jokers)
+                .balance(balance)
+                .build()
+                .score()
+                .chips
+        };
+
+        assert_eq!(score(Money(10)), Chips(16 + 2 * 13));
+        assert_eq!(score(Money(-10)), Chips(16));
+    }
+}
@@ -0,0 +1,13 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_business", name = "Business Card", rarity = Common, price = 4)]
+pub struct BusinessCardJoker;
+
+impl JokerKind for BusinessCardJoker {
+    fn on_scored(&mut self, scorer: &mut Scorer, index: usize) {
+        if scorer.played()[index].is_face() && scorer.roll("business", Chance::one_in(2)) {
+            scorer.money += Money(2);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_credit_card", name = "Credit Card", rarity = Common, price = 1)]
+pub struct CreditCardJoker;
+
+impl JokerKind for CreditCardJoker {
+    fn debt(&self) -> Money {
+        Money(20)
+    }
+}
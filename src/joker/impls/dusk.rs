@@ -0,0 +1,37 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_dusk", name = "Dusk", rarity = Uncommon, price = 5)]
+pub struct DuskJoker;
+
+impl JokerKind for DuskJoker {
+    fn retrigger_played(&self, scorer: &Scorer, _index: usize) -> usize {
+        usize::from(scorer.is_final_hand())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DuskJoker as This;
+
+    #[test]
+    fn only_final_hand() {
+        let jokers = jokers![5: This];
+        let played = [Card::new(Rank::Ace, Suit::Heart)];
+        let hands = HandTypeStates::default();
+
+        let score = Scorer::builder(&played, &[], &hands, &jokers)
+            .build()
+            .score();
+
+        assert_eq!(score.chips, Chips(5 + 11));
+
+        let score = Scorer::builder(&played, &[], &hands, &jokers)
+            .final_hand()
+            .build()
+            .score();
+
+        assert_eq!(score.chips, Chips(5 + 11 * 2));
+    }
+}
@@ -0,0 +1,32 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_egg", name = "Egg", rarity = Common, price = 4)]
+pub struct EggJoker {
+    pub value: Money,
+}
+
+impl JokerKind for EggJoker {
+    fn extra_value(&self) -> Money {
+        self.value
+    }
+
+    fn on_round_end(&mut self, _run: &mut Run) {
+        self.value += Money(3);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gains_sell_value() {
+        let mut run = Run::new(jokers![5: EggJoker::default()], Money(0));
+        assert_eq!(run.jokers.get(0).unwrap().sell_value(), Money(2));
+
+        run.end_round(&[]);
+        run.end_round(&[]);
+        assert_eq!(run.jokers.get(0).unwrap().sell_value(), Money(2 + 6));
+    }
+}
@@ -0,0 +1,13 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_faceless", name = "Faceless Joker", rarity = Common, price = 4)]
+pub struct FacelessJoker;
+
+impl JokerKind for FacelessJoker {
+    fn on_discard(&mut self, run: &mut Run, discarded: &[Card]) {
+        if discarded.iter().filter(|card| card.is_face()).count() >= 3 {
+            run.money += Money(5);
+        }
+    }
+}
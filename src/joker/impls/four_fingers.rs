@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_four_fingers", name = "Four Fingers", rarity = Uncommon, price = 7)]
+pub struct FourFingersJoker;
+
+impl JokerKind for FourFingersJoker {
+    fn blueprint_compatible(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,35 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_gift", name = "Gift Card", rarity = Uncommon, price = 6)]
+pub struct GiftCardJoker;
+
+impl JokerKind for GiftCardJoker {
+    fn on_round_end(&mut self, run: &mut Run) {
+        for joker in run.jokers.iter_mut() {
+            joker.add_extra_value(Money(1));
+        }
+
+        for consumable in run.consumables.iter_mut() {
+            consumable.extra_value += Money(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_sell_value_to_everything() {
+        let jokers = jokers![5: GiftCardJoker, JimboJoker];
+        let mut run = Run::new(jokers, Money(0));
+
+        run.consumables.push(Planet::Pluto.into()).unwrap();
+        run.end_round(&[]);
+
+        let values = run.jokers.iter().map(Joker::sell_value);
+        assert_eq!(values.collect::<Vec<_>>(), [Money(3 + 1), Money(1 + 1)]);
+        assert_eq!(run.consumables.get(0).unwrap().extra_value, Money(1));
+    }
+}
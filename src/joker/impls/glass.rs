@@ -0,0 +1,51 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, JokerInfo)]
+#[joker(id = "j_glass", name = "Glass Joker", rarity = Uncommon, price = 6)]
+pub struct GlassJoker {
+    pub x_mult: f64,
+}
+
+impl Default for GlassJoker {
+    fn default() -> Self {
+        Self { x_mult: 1.0 }
+    }
+}
+
+impl JokerKind for GlassJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Perishable
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.mult *= self.x_mult;
+    }
+
+    fn on_cards_destroyed(&mut self, _run: &mut Run, destroyed: &[Card]) {
+        let glass = destroyed
+            .iter()
+            .filter(|card| card.enhancement == Some(Enhancement::Glass));
+
+        self.x_mult += 0.75 * glass.count() as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn glass_destroyed() {
+        let mut run = Run::new(jokers![5: GlassJoker::default()], Money(0));
+        let glass = Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Glass)
+            .build();
+
+        run.destroy_cards(&[glass, Card::new(Two, Heart)]);
+
+        let score = run.play(&[Card::new(Ace, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.75));
+    }
+}
@@ -0,0 +1,16 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_greedy_joker", name = "Greedy Joker", rarity = Common, price = 5)]
+pub struct GreedyJoker;
+
+impl JokerKind for GreedyJoker {
+    fn on_scored(&mut self, scorer: &mut Scorer, index: usize) {
+        if scorer
+            .rules()
+            .is_suit(&scorer.played()[index], Suit::Diamond)
+        {
+            scorer.mult += 3;
+        }
+    }
+}
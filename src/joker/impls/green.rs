@@ -0,0 +1,48 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_green_joker", name = "Green Joker", rarity = Common, price = 4)]
+pub struct GreenJoker {
+    pub mult: u64,
+}
+
+impl JokerKind for GreenJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Perishable
+    }
+
+    fn before(&mut self, _scorer: &mut Scorer) {
+        self.mult += 1;
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.mult += self.mult;
+    }
+
+    fn on_discard(&mut self, _run: &mut Run, _discarded: &[Card]) {
+        self.mult = self.mult.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn hands_and_discards() {
+        let mut run = Run::new(jokers![5: GreenJoker::default()], Money(0));
+
+        run.discard(&[Card::new(Two, Heart)]);
+        run.play(&[Card::new(Ace, Heart)], &[]);
+
+        let score = run.play(&[Card::new(Ace, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.0 + 2.0));
+
+        run.discard(&[Card::new(Two, Heart)]);
+
+        let score = run.play(&[Card::new(Ace, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.0 + 2.0));
+    }
+}
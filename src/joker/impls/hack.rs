@@ -0,0 +1,12 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_hack", name = "Hack", rarity = Uncommon, price = 6)]
+pub struct HackJoker;
+
+impl JokerKind for HackJoker {
+    fn retrigger_played(&self, scorer: &Scorer, index: usize) -> usize {
+        let card = &scorer.played()[index];
+        usize::from(!card.is_stone() && card.rank <= Rank::Five)
+    }
+}
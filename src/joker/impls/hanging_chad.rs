@@ -0,0 +1,15 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_hanging_chad", name = "Hanging Chad", rarity = Common, price = 4)]
+pub struct HangingChadJoker;
+
+impl JokerKind for HangingChadJoker {
+    fn retrigger_played(&self, scorer: &Scorer, index: usize) -> usize {
+        if scorer.hand().scoring().first() == Some(&index) {
+            2
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, JokerInfo)]
+#[joker(id = "j_hologram", name = "Hologram", rarity = Uncommon, price = 7)]
+pub struct HologramJoker {
+    pub x_mult: f64,
+}
+
+impl Default for HologramJoker {
+    fn default() -> Self {
+        Self { x_mult: 1.0 }
+    }
+}
+
+impl JokerKind for HologramJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Perishable
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.mult *= self.x_mult;
+    }
+
+    fn on_cards_added(&mut self, _run: &mut Run, added: &[Card]) {
+        self.x_mult += 0.25 * added.len() as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn cards_added() {
+        let mut run = Run::new(jokers![5: HologramJoker::default()], Money(0));
+
+        run.add_cards(&[Card::new(Ace, Heart), Card::new(Two, Club)]);
+
+        let score = run.play(&[Card::new(Ace, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.5));
+    }
+}
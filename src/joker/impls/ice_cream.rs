@@ -0,0 +1,56 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, JokerInfo)]
+#[joker(id = "j_ice_cream", name = "Ice Cream", rarity = Common, price = 5)]
+pub struct IceCreamJoker {
+    pub chips: u64,
+}
+
+impl Default for IceCreamJoker {
+    fn default() -> Self {
+        Self { chips: 100 }
+    }
+}
+
+impl JokerKind for IceCreamJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Eternal
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.chips += self.chips;
+    }
+
+    fn is_spent(&self) -> bool {
+        self.chips == 0
+    }
+
+    fn after(&mut self, _scorer: &mut Scorer) {
+        self.chips = self.chips.saturating_sub(5);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn melts() {
+        let mut run = Run::new(jokers![5: IceCreamJoker::default()], Money(0));
+        let played = [Card::new(Ace, Heart)];
+
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11 + 100));
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11 + 95));
+    }
+
+    #[test]
+    fn melts_away() {
+        let mut run = Run::new(jokers![5: IceCreamJoker { chips: 5 }], Money(0));
+        let played = [Card::new(Ace, Heart)];
+
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11 + 5));
+        assert!(run.jokers.is_empty());
+    }
+}
@@ -1,22 +1,11 @@
 use super::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_joker", name = "Joker", rarity = Common, price = 2)]
 pub struct JimboJoker;
 
 impl JokerKind for JimboJoker {
-    fn name(&self) -> &'static str {
-        "Joker"
-    }
-
-    fn rarity(&self) -> Rarity {
-        Rarity::Common
-    }
-
-    fn price(&self) -> Money {
-        Money(2)
-    }
-
-    fn run_independent(&self, scorer: &mut Scorer) {
+    fn run_independent(&mut self, scorer: &mut Scorer) {
         scorer.mult += 4;
     }
 }
@@ -0,0 +1,50 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, JokerInfo)]
+#[joker(id = "j_lucky_cat", name = "Lucky Cat", rarity = Uncommon, price = 6)]
+pub struct LuckyCatJoker {
+    pub x_mult: f64,
+}
+
+impl Default for LuckyCatJoker {
+    fn default() -> Self {
+        Self { x_mult: 1.0 }
+    }
+}
+
+impl JokerKind for LuckyCatJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Perishable
+    }
+
+    fn on_lucky(&mut self, _scorer: &mut Scorer, _index: usize) {
+        self.x_mult += 0.25;
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.mult *= self.x_mult;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn lucky_triggers() {
+        let jokers = jokers![
+            5: LuckyCatJoker::default(),
+            OopsAllSixesJoker,
+            OopsAllSixesJoker,
+            OopsAllSixesJoker,
+        ];
+        let played = [Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Lucky)
+            .build()];
+
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &jokers);
+        assert_eq!(score.mult, Mult((1.0 + 20.0) * 1.25));
+    }
+}
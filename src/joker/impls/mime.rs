@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_mime", name = "Mime", rarity = Uncommon, price = 5)]
+pub struct MimeJoker;
+
+impl JokerKind for MimeJoker {
+    fn retrigger_held(&self, _scorer: &Scorer, _index: usize) -> usize {
+        1
+    }
+}
@@ -1,22 +1,33 @@
 use super::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_misprint", name = "Misprint", rarity = Common, price = 4)]
 pub struct MisprintJoker;
 
 impl JokerKind for MisprintJoker {
-    fn name(&self) -> &'static str {
-        "Misprint"
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        let mult = scorer.random_range("misprint", 0..=23);
+        scorer.mult += mult;
     }
+}
 
-    fn rarity(&self) -> Rarity {
-        Rarity::Common
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    fn price(&self) -> Money {
-        Money(4)
-    }
+    #[test]
+    fn seeded() {
+        let jokers = jokers![5: MisprintJoker];
+        let hands = HandTypeStates::default();
+        let score = |seed| {
+            Scorer::builder(&[], &[], &hands, &jokers)
+                .rng(RunRng::new(seed))
+                .build()
+                .score()
+                .mult
+        };
 
-    fn run_independent(&self, scorer: &mut Scorer) {
-        scorer.mult += rand::rng().random_range(0..=23);
+        assert_eq!(score(7), score(7));
+        assert!((0..16).map(score).any(|mult| mult != score(7)));
     }
 }
@@ -0,0 +1,12 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_oops", name = "Oops! All 6s", rarity = Uncommon, price = 4)]
+pub struct OopsAllSixesJoker;
+
+impl JokerKind for OopsAllSixesJoker {
+    // Read through `Probabilities`, which counts every copy.
+    fn blueprint_compatible(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,52 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_ride_the_bus", name = "Ride the Bus", rarity = Common, price = 6)]
+pub struct RideTheBusJoker {
+    /// Consecutive hands played without a scoring face card.
+    pub mult: u64,
+}
+
+impl JokerKind for RideTheBusJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Perishable
+    }
+
+    fn before(&mut self, scorer: &mut Scorer) {
+        let played = scorer.played();
+
+        if scorer.hand().scoring_cards(played).any(Card::is_face) {
+            self.mult = 0;
+        } else {
+            self.mult += 1;
+        }
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.mult += self.mult;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn resets_on_face_card() {
+        let mut run = Run::new(jokers![5: RideTheBusJoker::default()], Money(0));
+
+        let score = run.play(&[Card::new(Ace, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.0 + 1.0));
+
+        let score = run.play(&[Card::new(Two, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.0 + 2.0));
+
+        let score = run.play(&[Card::new(King, Heart)], &[]);
+        assert_eq!(score.mult, Mult(1.0));
+
+        let joker = run.jokers.get(0).unwrap();
+        assert_eq!(joker.downcast_ref::<RideTheBusJoker>().unwrap().mult, 0);
+    }
+}
@@ -0,0 +1,26 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_runner", name = "Runner", rarity = Common, price = 5)]
+pub struct RunnerJoker {
+    pub chips: u64,
+}
+
+impl JokerKind for RunnerJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Perishable
+    }
+
+    fn before(&mut self, scorer: &mut Scorer) {
+        if matches!(
+            scorer.hand().hand_type(),
+            HandType::Straight | HandType::StraightFlush,
+        ) {
+            self.chips += 15;
+        }
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
+        scorer.chips += self.chips;
+    }
+}
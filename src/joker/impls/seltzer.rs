@@ -0,0 +1,49 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, JokerInfo)]
+#[joker(id = "j_selzer", name = "Seltzer", rarity = Uncommon, price = 6)]
+pub struct SeltzerJoker {
+    pub hands_left: u8,
+}
+
+impl Default for SeltzerJoker {
+    fn default() -> Self {
+        Self { hands_left: 10 }
+    }
+}
+
+impl JokerKind for SeltzerJoker {
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        sticker != Sticker::Eternal
+    }
+
+    fn retrigger_played(&self, _scorer: &Scorer, _index: usize) -> usize {
+        usize::from(self.hands_left > 0)
+    }
+
+    fn is_spent(&self) -> bool {
+        self.hands_left == 0
+    }
+
+    fn after(&mut self, _scorer: &mut Scorer) {
+        self.hands_left = self.hands_left.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+    use Suit::*;
+
+    #[test]
+    fn runs_out() {
+        let seltzer = SeltzerJoker { hands_left: 1 };
+        let mut run = Run::new(jokers![5: seltzer], Money(0));
+        let played = [Card::new(Ace, Heart)];
+
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11 * 2));
+        assert!(run.jokers.is_empty());
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11));
+    }
+}
@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_shortcut", name = "Shortcut", rarity = Uncommon, price = 7)]
+pub struct ShortcutJoker;
+
+impl JokerKind for ShortcutJoker {
+    fn blueprint_compatible(&self) -> bool {
+        false
+    }
+}
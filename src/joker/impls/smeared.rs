@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_smeared", name = "Smeared Joker", rarity = Uncommon, price = 7)]
+pub struct SmearedJoker;
+
+impl JokerKind for SmearedJoker {
+    fn blueprint_compatible(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,11 @@
+use super::prelude::*;
+
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_sock_and_buskin", name = "Sock and Buskin", rarity = Uncommon, price = 6)]
+pub struct SockAndBuskinJoker;
+
+impl JokerKind for SockAndBuskinJoker {
+    fn retrigger_played(&self, scorer: &Scorer, index: usize) -> usize {
+        usize::from(scorer.played()[index].is_face())
+    }
+}
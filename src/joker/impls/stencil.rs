@@ -1,22 +1,11 @@
 use super::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default, JokerInfo)]
+#[joker(id = "j_stencil", name = "Joker Stencil", rarity = Uncommon, price = 8)]
 pub struct StencilJoker;
 
 impl JokerKind for StencilJoker {
-    fn name(&self) -> &'static str {
-        "Joker Stencil"
-    }
-
-    fn rarity(&self) -> Rarity {
-        Rarity::Uncommon
-    }
-
-    fn price(&self) -> Money {
-        Money(8)
-    }
-
-    fn run_independent(&self, scorer: &mut Scorer) {
+    fn run_independent(&mut self, scorer: &mut Scorer) {
         let empty = scorer.jokers.free_len();
         let stencils = scorer.jokers.kind::<Self>().count();
         let mult = empty + stencils;
@@ -33,10 +22,10 @@ mod tests {
     #[test]
     fn only_joker() {
         let jokers = jokers![5: This];
-        let mut scorer = Scorer::new(jokers);
+        let mut scorer = Scorer::new(jokers, &[], &[], &HandTypeStates::default());
 
         This.run_independent(&mut scorer);
 
-        assert_eq!(scorer.mult, Mult(5));
+        assert_eq!(scorer.mult, Mult(5.0));
     }
 }
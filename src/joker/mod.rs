@@ -1,20 +1,42 @@
-use crate::{Edition, HasEdition, Money};
-use std::{any::Any, fmt::Debug, rc::Rc};
+use crate::{Blind, Card, Edition, HasEdition, Money, Run, Slate};
+use std::{
+    any::Any,
+    fmt::Debug,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
+#[cfg(not(feature = "sync"))]
+use std::rc::Rc;
+#[cfg(feature = "sync")]
+use std::sync::Arc as Rc;
+
+mod distribution;
 mod impls;
+mod registry;
 mod scorer;
 mod slate;
+mod sticker;
+mod trace;
 
+pub use distribution::*;
 pub use impls::*;
+pub use registry::*;
 pub use scorer::*;
 pub use slate::*;
+pub use sticker::*;
+pub use trace::*;
+
+pub use balatro_macros::JokerInfo;
 
 #[derive(Debug, Clone)]
 pub struct Joker {
-    // TODO: Add sync feature.
+    /// Tells jokers apart while their kinds are swapped out by copy-on-write. Shared by
+    /// clones, like those in older snapshots of a [`Slate`].
+    key: u64,
     kind: Rc<dyn JokerKind>,
     edition: Option<Edition<Self>>,
-    // TODO: Add stickers.
+    stickers: Stickers,
+    extra_value: Money,
 }
 
 impl HasEdition for Joker {
@@ -28,9 +50,14 @@ impl HasEdition for Joker {
 
 impl Joker {
     pub fn new(kind: impl JokerKind) -> Self {
+        static NEXT_KEY: AtomicU64 = AtomicU64::new(0);
+
         Self {
+            key: NEXT_KEY.fetch_add(1, Ordering::Relaxed),
             kind: Rc::new(kind),
             edition: None,
+            stickers: Stickers::default(),
+            extra_value: Money::ZERO,
         }
     }
 
@@ -38,11 +65,48 @@ impl Joker {
         JokerBuilder(Self::new(kind))
     }
 
+    pub fn kind(&self) -> &dyn JokerKind {
+        &*self.kind
+    }
+
+    /// Clones the kind first if it's shared with another copy of this joker, such as
+    /// one in an older snapshot of a [`Slate`], so that copy doesn't see the change.
+    pub fn kind_mut(&mut self) -> &mut dyn JokerKind {
+        if Rc::get_mut(&mut self.kind).is_none() {
+            self.kind = self.kind.clone_kind();
+        }
+
+        Rc::get_mut(&mut self.kind).expect("kind to be unique")
+    }
+
     pub fn is<J: JokerKind>(&self) -> bool {
         let kind: &dyn Any = &*self.kind;
         kind.is::<J>()
     }
 
+    pub fn downcast_ref<J: JokerKind>(&self) -> Option<&J> {
+        let kind: &dyn Any = &*self.kind;
+        kind.downcast_ref()
+    }
+
+    pub fn downcast_mut<J: JokerKind>(&mut self) -> Option<&mut J> {
+        if !self.is::<J>() {
+            return None;
+        }
+
+        let kind: &mut dyn Any = self.kind_mut();
+        kind.downcast_mut()
+    }
+
+    pub(crate) fn same(&self, other: &Joker) -> bool {
+        self.key == other.key
+    }
+
+    /// The id this joker's kind is registered under in [`JokerRegistry`].
+    pub fn id(&self) -> &'static str {
+        self.kind.id()
+    }
+
     pub fn name(&self) -> &'static str {
         self.kind.name()
     }
@@ -51,9 +115,52 @@ impl Joker {
         self.kind.rarity()
     }
 
+    /// What it costs to buy, including any edition.
     pub fn price(&self) -> Money {
-        // TODO: If rental, Money(1).
-        self.kind.price()
+        if self.stickers.rental {
+            return Money(1);
+        }
+
+        let edition = match self.edition {
+            Some(Edition::Foil(())) => Money(2),
+            Some(Edition::Holographic(())) => Money(3),
+            Some(Edition::Polychrome(()) | Edition::Negative(())) => Money(5),
+            None => Money::ZERO,
+        };
+
+        self.kind.price() + edition
+    }
+
+    /// Half the price, rounded down but at least $1, plus any value it has gained.
+    pub fn sell_value(&self) -> Money {
+        let base = Money((self.price().0 / 2).max(1));
+        base + self.extra_value + self.kind.extra_value()
+    }
+
+    /// Adds to the sell value, as Gift Card does.
+    pub fn add_extra_value(&mut self, value: Money) {
+        self.extra_value += value;
+    }
+
+    pub fn stickers(&self) -> Stickers {
+        self.stickers
+    }
+
+    pub fn is_debuffed(&self) -> bool {
+        self.stickers.is_debuffed()
+    }
+
+    /// Counts down Perishable and charges for Rental, returning what the rent was.
+    pub(crate) fn end_round(&mut self) -> Money {
+        if let Some(rounds) = &mut self.stickers.perishable {
+            *rounds = rounds.saturating_sub(1);
+        }
+
+        if self.stickers.rental {
+            Money(3)
+        } else {
+            Money::ZERO
+        }
     }
 }
 
@@ -66,19 +173,177 @@ impl JokerBuilder {
         self
     }
 
+    /// Panics if the joker's kind isn't compatible with the sticker.
+    pub fn sticker(mut self, sticker: Sticker) -> Self {
+        assert!(
+            self.0.kind.sticker_compatible(sticker),
+            "{} can't be {sticker:?}",
+            self.0.name(),
+        );
+
+        self.0.stickers.add(sticker);
+        self
+    }
+
     pub fn build(self) -> Joker {
         self.0
     }
 }
 
-pub trait JokerKind: Any + Debug {
+/// What a joker is, as opposed to what it does. Usually derived, which also adds the
+/// joker to [`JokerRegistry`]:
+///
+/// ```
+/// # use balatro::*;
+/// #[derive(Debug, Clone, Default, JokerInfo)]
+/// #[joker(id = "my_joker", name = "My Joker", rarity = Common, price = 2)]
+/// struct MyJoker;
+///
+/// impl JokerKind for MyJoker {}
+///
+/// assert!(JokerRegistry::create("my_joker").unwrap().is::<MyJoker>());
+/// ```
+pub trait JokerInfo {
+    /// Stable across versions, and the same as the game's own key for built-in jokers.
+    fn id(&self) -> &'static str;
     fn name(&self) -> &'static str;
     fn rarity(&self) -> Rarity;
     fn price(&self) -> Money;
+}
+
+pub trait JokerKind: JokerInfo + Any + Debug + clone::CloneKind + sync::MaybeSync {
+    /// Sell value this joker's own ability has built up, like Egg's.
+    fn extra_value(&self) -> Money {
+        Money::ZERO
+    }
+
+    /// Whether this joker can be given the sticker. Jokers that are only worth having
+    /// for a while can't be Eternal, and jokers that grow can't be Perishable.
+    fn sticker_compatible(&self, sticker: Sticker) -> bool {
+        let _ = sticker;
+        true
+    }
+
+    /// Whether Blueprint and Brainstorm can copy this joker's ability.
+    fn blueprint_compatible(&self) -> bool {
+        true
+    }
 
-    fn run_independent(&self, scorer: &mut Scorer) {
+    /// The index of the joker whose ability this one uses in place of its own, given
+    /// that it sits at `index` in `jokers`.
+    fn copies(&self, jokers: &Slate<Joker>, index: usize) -> Option<usize> {
+        let _ = (jokers, index);
+        None
+    }
+
+    /// After the hand is known, before any cards score.
+    fn before(&mut self, scorer: &mut Scorer) {
+        let _ = scorer;
+    }
+
+    /// Each time the played card at `index` triggers, after its own effects.
+    fn on_scored(&mut self, scorer: &mut Scorer, index: usize) {
+        let _ = (scorer, index);
+    }
+
+    /// Each time the card held in hand at `index` triggers, after its own effects.
+    fn on_held(&mut self, scorer: &mut Scorer, index: usize) {
+        let _ = (scorer, index);
+    }
+
+    /// Each time the Lucky card played at `index` triggers either of its chances.
+    fn on_lucky(&mut self, scorer: &mut Scorer, index: usize) {
+        let _ = (scorer, index);
+    }
+
+    fn run_independent(&mut self, scorer: &mut Scorer) {
         let _ = scorer;
     }
+
+    /// After all scoring for the hand is done.
+    fn after(&mut self, scorer: &mut Scorer) {
+        let _ = scorer;
+    }
+
+    /// Extra times to trigger the played card at `index`, if it scores.
+    fn retrigger_played(&self, scorer: &Scorer, index: usize) -> usize {
+        let _ = (scorer, index);
+        0
+    }
+
+    /// Extra times to trigger the card held in hand at `index`.
+    fn retrigger_held(&self, scorer: &Scorer, index: usize) -> usize {
+        let _ = (scorer, index);
+        0
+    }
+
+    /// Whether this joker has used itself up, like Ice Cream once it melts. It's
+    /// removed as soon as any of its hooks leaves it spent.
+    fn is_spent(&self) -> bool {
+        false
+    }
+
+    /// How far below the run's debt floor this joker lets money go.
+    fn debt(&self) -> Money {
+        Money::ZERO
+    }
+
+    fn on_discard(&mut self, run: &mut Run, discarded: &[Card]) {
+        let _ = (run, discarded);
+    }
+
+    fn on_blind_selected(&mut self, run: &mut Run, blind: Blind) {
+        let _ = (run, blind);
+    }
+
+    fn on_round_end(&mut self, run: &mut Run) {
+        let _ = run;
+    }
+
+    /// When this joker is sold, while it is still in the slate.
+    fn on_sell(&mut self, run: &mut Run) {
+        let _ = run;
+    }
+
+    fn on_reroll(&mut self, run: &mut Run) {
+        let _ = run;
+    }
+
+    fn on_cards_destroyed(&mut self, run: &mut Run, destroyed: &[Card]) {
+        let _ = (run, destroyed);
+    }
+
+    fn on_cards_added(&mut self, run: &mut Run, added: &[Card]) {
+        let _ = (run, added);
+    }
+}
+
+mod clone {
+    use super::{JokerKind, Rc};
+
+    // Lets jokers be cloned behind `dyn JokerKind`, for copy-on-write of their state.
+    pub trait CloneKind {
+        fn clone_kind(&self) -> Rc<dyn JokerKind>;
+    }
+
+    impl<J: JokerKind + Clone> CloneKind for J {
+        fn clone_kind(&self) -> Rc<dyn JokerKind> {
+            Rc::new(self.clone())
+        }
+    }
+}
+
+mod sync {
+    // Jokers only need to be thread-safe when `Joker` is.
+    #[cfg(feature = "sync")]
+    pub trait MaybeSync: Send + Sync {}
+    #[cfg(feature = "sync")]
+    impl<T: Send + Sync> MaybeSync for T {}
+
+    #[cfg(not(feature = "sync"))]
+    pub trait MaybeSync {}
+    #[cfg(not(feature = "sync"))]
+    impl<T> MaybeSync for T {}
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -0,0 +1,197 @@
+use super::{Joker, JokerKind, Rarity};
+use crate::Money;
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, PoisonError, RwLock},
+};
+use thiserror::Error;
+
+/// Everything needed to refer to a kind of joker without knowing its Rust type.
+#[derive(Debug, Copy, Clone)]
+pub struct JokerEntry {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub rarity: Rarity,
+    pub price: Money,
+    new: fn() -> Joker,
+}
+
+impl JokerEntry {
+    /// The entry for `J`, under the id it gives through [`JokerInfo`](super::JokerInfo).
+    pub fn of<J: JokerKind + Default>() -> Self {
+        let kind = J::default();
+
+        Self {
+            id: kind.id(),
+            name: kind.name(),
+            rarity: kind.rarity(),
+            price: kind.price(),
+            new: || Joker::new(J::default()),
+        }
+    }
+
+    /// A fresh joker of this kind, with no edition or stickers.
+    pub fn create(&self) -> Joker {
+        (self.new)()
+    }
+}
+
+/// Submitted by `#[derive(JokerInfo)]` for every joker it's used on.
+#[doc(hidden)]
+pub struct JokerRegistration(pub fn() -> JokerEntry);
+
+inventory::collect!(JokerRegistration);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
+pub enum RegistryError {
+    #[error("a joker with the id {0:?} is already registered")]
+    DuplicateId(&'static str),
+}
+
+/// The jokers known by id. Every joker that derives [`JokerInfo`](super::JokerInfo) is
+/// in it from the start, including those from other crates; any others can be added
+/// with [`JokerRegistry::register`].
+pub struct JokerRegistry {
+    entries: Vec<JokerEntry>,
+    by_id: HashMap<&'static str, usize>,
+}
+
+static REGISTRY: LazyLock<RwLock<JokerRegistry>> = LazyLock::new(|| {
+    let mut registry = JokerRegistry {
+        entries: Vec::new(),
+        by_id: HashMap::new(),
+    };
+
+    let mut derived = inventory::iter::<JokerRegistration>
+        .into_iter()
+        .map(|registration| (registration.0)())
+        .collect::<Vec<_>>();
+
+    // collected in no particular order
+    derived.sort_by_key(|entry| entry.id);
+
+    for entry in derived {
+        if let Err(error) = registry.insert(entry) {
+            panic!("{error}");
+        }
+    }
+
+    RwLock::new(registry)
+});
+
+impl JokerRegistry {
+    pub fn register(entry: JokerEntry) -> Result<(), RegistryError> {
+        let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+        registry.insert(entry)
+    }
+
+    pub fn get(id: &str) -> Option<JokerEntry> {
+        Self::read(|registry| registry.by_id.get(id).map(|&i| registry.entries[i]))
+    }
+
+    pub fn create(id: &str) -> Option<Joker> {
+        Self::get(id).map(|entry| entry.create())
+    }
+
+    /// Every registered joker, derived ones first in order of id.
+    pub fn entries() -> Vec<JokerEntry> {
+        Self::read(|registry| registry.entries.clone())
+    }
+
+    fn read<T>(f: impl FnOnce(&Self) -> T) -> T {
+        f(&REGISTRY.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn insert(&mut self, entry: JokerEntry) -> Result<(), RegistryError> {
+        if self.by_id.contains_key(entry.id) {
+            return Err(RegistryError::DuplicateId(entry.id));
+        }
+
+        self.by_id.insert(entry.id, self.entries.len());
+        self.entries.push(entry);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JimboJoker, JokerInfo, SeltzerJoker};
+
+    #[derive(Debug, Default, Clone, JokerInfo)]
+    #[joker(id = "custom_joker", name = "Custom", rarity = Legendary, price = 20)]
+    struct CustomJoker;
+
+    impl JokerKind for CustomJoker {}
+
+    #[derive(Debug, Default, Clone)]
+    struct ManualJoker;
+
+    impl JokerInfo for ManualJoker {
+        fn id(&self) -> &'static str {
+            "manual_joker"
+        }
+
+        fn name(&self) -> &'static str {
+            "Manual"
+        }
+
+        fn rarity(&self) -> Rarity {
+            Rarity::Common
+        }
+
+        fn price(&self) -> Money {
+            Money(1)
+        }
+    }
+
+    impl JokerKind for ManualJoker {}
+
+    #[test]
+    fn builtin() {
+        let entry = JokerRegistry::get("j_joker").unwrap();
+
+        assert_eq!(entry.name, "Joker");
+        assert_eq!(entry.price, Money(2));
+        assert!(entry.create().is::<JimboJoker>());
+        assert_eq!(Joker::new(SeltzerJoker::default()).id(), "j_selzer");
+        assert!(JokerRegistry::get("j_unknown").is_none());
+
+        let builtin = JokerRegistry::entries()
+            .into_iter()
+            .filter(|e| e.id.starts_with("j_"));
+        assert_eq!(builtin.count(), 32);
+    }
+
+    #[test]
+    fn derived() {
+        assert!(
+            JokerRegistry::create("custom_joker")
+                .unwrap()
+                .is::<CustomJoker>()
+        );
+        assert!(JokerRegistry::entries().len() >= 33);
+    }
+
+    #[test]
+    fn register() {
+        assert!(JokerRegistry::get("manual_joker").is_none());
+
+        JokerRegistry::register(JokerEntry::of::<ManualJoker>()).unwrap();
+
+        assert!(
+            JokerRegistry::create("manual_joker")
+                .unwrap()
+                .is::<ManualJoker>()
+        );
+        assert_eq!(
+            JokerRegistry::register(JokerEntry::of::<ManualJoker>()),
+            Err(RegistryError::DuplicateId("manual_joker"))
+        );
+        assert_eq!(
+            JokerRegistry::register(JokerEntry::of::<CustomJoker>()),
+            Err(RegistryError::DuplicateId("custom_joker"))
+        );
+    }
+}
@@ -1,22 +1,910 @@
 use super::*;
-use crate::{Chips, Mult, Slate};
+use crate::{
+    Card, Chance, Chips, DetectedHand, Enhancement, HandRules, HandType, HandTypeStates, Mult,
+    Probabilities, RunRng, Score, Seal, Slate,
+};
+use rand::Rng;
+use std::ops::RangeInclusive;
+
+/// Scores a hand with [`RunRng::default`], so random effects like Misprint's come out
+/// the same on every call. Use [`ScorerBuilder::rng`] to score with a run's randomness.
+pub fn score_hand(
+    played: &[Card],
+    held: &[Card],
+    hands: &HandTypeStates,
+    jokers: &Slate<Joker>,
+) -> HandScore {
+    Scorer::builder(played, held, hands, jokers).build().score()
+}
+
+#[derive(Debug, Clone)]
+pub struct HandScore {
+    pub hand: DetectedHand,
+    pub chips: Chips,
+    pub mult: Mult,
+    pub money: Money,
+    /// With any state the jokers changed while scoring.
+    pub jokers: Slate<Joker>,
+    /// Advanced past whatever was drawn while scoring.
+    pub rng: RunRng,
+    /// Indices of played cards destroyed by the hand, such as shattered Glass cards.
+    pub destroyed: Vec<usize>,
+    /// Only recorded when asked for with [`ScorerBuilder::trace`].
+    pub trace: Option<Vec<TraceStep>>,
+}
+
+impl HandScore {
+    pub fn hand_type(&self) -> HandType {
+        self.hand.hand_type()
+    }
+
+    pub fn score(&self) -> Score {
+        self.chips * self.mult
+    }
+}
 
 // TODO: Which parts are mut?
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
-pub struct Scorer {
+pub struct Scorer<'a> {
     pub jokers: Slate<Joker>,
     pub chips: Chips,
     pub mult: Mult,
+    pub money: Money,
+    pub rng: RunRng,
+    played: &'a [Card],
+    held: &'a [Card],
+    hand: DetectedHand,
+    rules: HandRules,
+    probabilities: Probabilities,
+    final_hand: bool,
+    balance: Money,
+    trace: Option<Vec<TraceStep>>,
+    draws: Draws,
+    destroyed: Vec<usize>,
 }
 
-impl Scorer {
-    pub(crate) fn new(jokers: Slate<Joker>) -> Self {
+impl<'a> Scorer<'a> {
+    pub fn builder(
+        played: &'a [Card],
+        held: &'a [Card],
+        hands: &'a HandTypeStates,
+        jokers: &Slate<Joker>,
+    ) -> ScorerBuilder<'a> {
+        ScorerBuilder(Self::new(jokers.clone(), played, held, hands))
+    }
+
+    pub(crate) fn new(
+        jokers: Slate<Joker>,
+        played: &'a [Card],
+        held: &'a [Card],
+        hands: &HandTypeStates,
+    ) -> Self {
+        let rules = HandRules::new(&jokers);
+        let probabilities = Probabilities::new(&jokers);
+        let hand = HandType::detect_with(played, rules);
+        let (chips, mult) = hands.get(hand.hand_type()).score();
+
         Self {
             jokers,
-            // TODO: Do the hand type calculations before this and start with the hand's score.
-            chips: Chips(1),
-            mult: Mult(1),
+            chips,
+            mult,
+            money: Money(0),
+            rng: RunRng::default(),
+            played,
+            held,
+            hand,
+            rules,
+            probabilities,
+            final_hand: false,
+            balance: Money::ZERO,
+            trace: None,
+            draws: Draws::Random,
+            destroyed: Vec::new(),
         }
     }
+
+    pub fn played(&self) -> &'a [Card] {
+        self.played
+    }
+
+    pub fn held(&self) -> &'a [Card] {
+        self.held
+    }
+
+    pub fn hand(&self) -> &DetectedHand {
+        &self.hand
+    }
+
+    pub fn rules(&self) -> HandRules {
+        self.rules
+    }
+
+    pub fn probabilities(&self) -> Probabilities {
+        self.probabilities
+    }
+
+    /// Rolls the listed `chance` on the `key` stream, scaled by [`Self::probabilities`].
+    ///
+    /// Effects must draw through this or [`Self::random_range`] rather than from
+    /// [`Self::rng`] directly, or [`Self::distribution`] can't see them.
+    pub fn roll(&mut self, key: &'static str, chance: Chance) -> bool {
+        let chance = self.probabilities.scale(chance);
+
+        match &mut self.draws {
+            Draws::Random => chance.roll(self.rng.stream(key)),
+            Draws::Path(_) if chance.is_certain() || chance.numerator() == 0 => chance.is_certain(),
+            Draws::Path(path) => {
+                let p = chance.probability();
+                path.choose(|| vec![p, 1.0 - p]) == 0
+            }
+        }
+    }
+
+    pub fn random_range(&mut self, key: &'static str, range: RangeInclusive<u64>) -> u64 {
+        match &mut self.draws {
+            Draws::Random => self.rng.stream(key).random_range(range),
+            Draws::Path(path) => {
+                let len = (range.end() - range.start() + 1) as usize;
+                let option = path.choose(|| vec![1.0 / len as f64; len]);
+
+                range.start() + option as u64
+            }
+        }
+    }
+
+    /// The player's money, including what this hand has earned so far.
+    pub fn balance(&self) -> Money {
+        self.balance + self.money
+    }
+
+    /// Whether this is the last hand that can be played this round.
+    pub fn is_final_hand(&self) -> bool {
+        self.final_hand
+    }
+
+    pub fn score(self) -> HandScore {
+        self.finish().0
+    }
+
+    /// Scores the hand under every combination of random outcomes, without drawing
+    /// from [`Self::rng`]. Fails rather than enumerating more than `limit` outcomes.
+    ///
+    /// Glass cards shattering isn't enumerated, since nothing can see it until the hand
+    /// has been scored.
+    pub fn distribution(self, limit: usize) -> Result<ScoreDistribution, TooManyOutcomes> {
+        let mut outcomes = Vec::new();
+        let mut path = Some(Path::default());
+
+        while let Some(current) = path {
+            if outcomes.len() == limit {
+                return Err(TooManyOutcomes { limit });
+            }
+
+            let mut scorer = self.clone();
+            scorer.trace = None;
+            scorer.draws = Draws::Path(current);
+
+            let (score, Draws::Path(current)) = scorer.finish() else {
+                unreachable!("draws to stay on the path");
+            };
+
+            outcomes.push((score.score(), current.probability()));
+            path = current.advance();
+        }
+
+        Ok(ScoreDistribution::new(outcomes))
+    }
+
+    fn finish(mut self) -> (HandScore, Draws) {
+        self.score_before();
+        self.score_played();
+        self.score_held();
+        self.score_jokers();
+        self.score_destroyed();
+        self.each_joker(|joker, this| joker.after(this));
+
+        let score = HandScore {
+            hand: self.hand,
+            chips: self.chips,
+            mult: self.mult,
+            money: self.money,
+            jokers: self.jokers,
+            rng: self.rng,
+            destroyed: self.destroyed,
+            trace: self.trace,
+        };
+
+        (score, self.draws)
+    }
+
+    fn score_before(&mut self) {
+        self.each_joker(|joker, this| joker.before(this));
+    }
+
+    fn score_played(&mut self) {
+        let played = self.played;
+
+        for index in self.hand.scoring().to_vec() {
+            let card = &played[index];
+            let retriggers = self.retriggers(card, |kind, this| kind.retrigger_played(this, index));
+
+            self.repeated(TraceSource::Played(index), retriggers, |this, source| {
+                this.score_card(source, index);
+                this.each_joker(|joker, this| joker.on_scored(this, index));
+            });
+        }
+    }
+
+    fn score_card(&mut self, source: TraceSource, index: usize) {
+        let card = &self.played[index];
+        let mut lucky = false;
+
+        self.traced(source, TraceReason::Rank, |this| this.chips += card.chips());
+
+        if let Some(enhancement) = card.enhancement {
+            self.traced(
+                source,
+                TraceReason::Enhancement(enhancement),
+                |this| match enhancement {
+                    Enhancement::Bonus => this.chips += 30,
+                    Enhancement::Mult => this.mult += 4,
+                    Enhancement::Glass => this.mult *= 2,
+                    Enhancement::Lucky => {
+                        if this.roll("lucky_mult", Chance::one_in(5)) {
+                            this.mult += 20;
+                            lucky = true;
+                        }
+
+                        if this.roll("lucky_money", Chance::one_in(15)) {
+                            this.money += Money(20);
+                            lucky = true;
+                        }
+                    }
+                    // Stone chips replace the rank's, and Steel and Gold only work held.
+                    Enhancement::Wild
+                    | Enhancement::Stone
+                    | Enhancement::Steel
+                    | Enhancement::Gold => {}
+                },
+            );
+        }
+
+        if lucky {
+            self.each_joker(|joker, this| joker.on_lucky(this, index));
+        }
+
+        if let Some(seal) = card.seal {
+            self.traced(source, TraceReason::Seal(seal), |this| {
+                if seal == Seal::Gold {
+                    this.money += Money(3);
+                }
+            });
+        }
+
+        self.score_edition(source, card);
+        self.score_edition_x_mult(source, card);
+    }
+
+    fn score_held(&mut self) {
+        let held = self.held;
+
+        for (index, card) in held.iter().enumerate() {
+            let retriggers = self.retriggers(card, |kind, this| kind.retrigger_held(this, index));
+
+            self.repeated(TraceSource::Held(index), retriggers, |this, source| {
+                this.score_held_card(source, card);
+                this.each_joker(|joker, this| joker.on_held(this, index));
+            });
+        }
+    }
+
+    fn score_held_card(&mut self, source: TraceSource, card: &Card) {
+        if card.enhancement == Some(Enhancement::Steel) {
+            self.traced(
+                source,
+                TraceReason::Enhancement(Enhancement::Steel),
+                |this| {
+                    this.mult *= 1.5;
+                },
+            );
+        }
+    }
+
+    /// Joker editions that add to the score apply before the joker's ability, and
+    /// Polychrome after it, as in the game.
+    fn score_jokers(&mut self) {
+        each_joker(
+            self,
+            |this| &mut this.jokers,
+            |index, kind, this| {
+                let source = TraceSource::Joker(index);
+                let joker = this.jokers.get(index).cloned().expect("joker at index");
+
+                this.score_edition(source, &joker);
+                this.traced(source, TraceReason::Ability, |this| {
+                    kind.run_independent(this);
+                });
+                this.score_edition_x_mult(source, &joker);
+            },
+        );
+    }
+
+    /// Foil and Holographic editions.
+    fn score_edition<T: HasEdition<Scoring = ()>>(&mut self, source: TraceSource, item: &T) {
+        self.traced(source, TraceReason::Edition, |this| match item.edition() {
+            Some(Edition::Foil(())) => this.chips += 50,
+            Some(Edition::Holographic(())) => this.mult += 10,
+            Some(Edition::Polychrome(()) | Edition::Negative(_)) | None => {}
+        });
+    }
+
+    /// Polychrome editions.
+    fn score_edition_x_mult<T: HasEdition<Scoring = ()>>(&mut self, source: TraceSource, item: &T) {
+        self.traced(source, TraceReason::Edition, |this| {
+            if let Some(Edition::Polychrome(())) = item.edition() {
+                this.mult *= 1.5;
+            }
+        });
+    }
+
+    /// Glass cards that scored may shatter once everything has scored.
+    fn score_destroyed(&mut self) {
+        if let Draws::Path(_) = self.draws {
+            return;
+        }
+
+        for index in self.hand.scoring().to_vec() {
+            if self.played[index].enhancement == Some(Enhancement::Glass)
+                && self.roll("glass", Chance::one_in(4))
+            {
+                self.destroyed.push(index);
+            }
+        }
+    }
+
+    fn each_joker(&mut self, mut f: impl FnMut(&mut dyn JokerKind, &mut Self)) {
+        each_joker(
+            self,
+            |this| &mut this.jokers,
+            |index, kind, this| {
+                this.traced(TraceSource::Joker(index), TraceReason::Ability, |this| {
+                    f(kind, this);
+                });
+            },
+        );
+    }
+
+    /// Extra times the card held at `index` triggers, for effects outside of scoring
+    /// like Gold cards paying out at the end of the round.
+    pub(crate) fn held_retriggers(&self, index: usize) -> usize {
+        let card = &self.held[index];
+        self.retriggers(card, |kind, this| kind.retrigger_held(this, index))
+    }
+
+    fn retriggers(&self, card: &Card, f: impl Fn(&dyn JokerKind, &Self) -> usize) -> usize {
+        let seal = usize::from(card.seal == Some(Seal::Red));
+        let jokers = (0..self.jokers.len())
+            .filter_map(|index| self.jokers.ability(index))
+            .map(|joker| f(joker.kind(), self));
+
+        seal + jokers.sum::<usize>()
+    }
+
+    /// Triggers a card once and then again for each retrigger. Retriggers are only
+    /// recorded in the trace when the card actually did something on that trigger.
+    fn repeated(
+        &mut self,
+        source: TraceSource,
+        retriggers: usize,
+        mut f: impl FnMut(&mut Self, TraceSource),
+    ) {
+        for retrigger in 0..=retriggers {
+            let (chips, mult) = (self.chips, self.mult);
+            let len = self.trace.as_ref().map(Vec::len);
+
+            f(self, source);
+
+            if let (Some(trace), Some(len)) = (&mut self.trace, len)
+                && retrigger > 0
+                && trace.len() > len
+            {
+                let step = TraceStep {
+                    source,
+                    reason: TraceReason::Retrigger,
+                    before: (chips, mult),
+                    after: (chips, mult),
+                    money: Money(0),
+                };
+
+                trace.insert(len, step);
+            }
+        }
+    }
+
+    /// Runs an effect, recording it in the trace if it changed anything.
+    fn traced(&mut self, source: TraceSource, reason: TraceReason, f: impl FnOnce(&mut Self)) {
+        let (chips, mult, money) = (self.chips, self.mult, self.money);
+
+        f(self);
+
+        if let Some(trace) = &mut self.trace
+            && (chips, mult, money) != (self.chips, self.mult, self.money)
+        {
+            trace.push(TraceStep {
+                source,
+                reason,
+                before: (chips, mult),
+                after: (self.chips, self.mult),
+                money: self.money - money,
+            });
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScorerBuilder<'a>(Scorer<'a>);
+
+impl<'a> ScorerBuilder<'a> {
+    pub fn trace(mut self) -> Self {
+        self.0.trace = Some(Vec::new());
+        self
+    }
+
+    pub fn final_hand(mut self) -> Self {
+        self.0.final_hand = true;
+        self
+    }
+
+    /// The player's money before the hand.
+    pub fn balance(mut self, balance: Money) -> Self {
+        self.0.balance = balance;
+        self
+    }
+
+    /// Draws from `rng` rather than a fresh one with the default seed.
+    pub fn rng(mut self, rng: RunRng) -> Self {
+        self.0.rng = rng;
+        self
+    }
+
+    pub fn build(self) -> Scorer<'a> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rank::*, Suit::*};
+
+    #[test]
+    fn seeded_from_hand_level() {
+        let played = [Card::new(Two, Heart), Card::new(Two, Spade)];
+        let hands = HandTypeStates::default().level_up(HandType::Pair);
+        let score = score_hand(&played, &[], &hands, &Slate::new(5));
+
+        assert_eq!(score.hand_type(), HandType::Pair);
+        assert_eq!(score.chips, Chips(20 + 2 + 2));
+        assert_eq!(score.mult, Mult(4.0));
+        assert_eq!(score.score(), Score::from(96));
+    }
+
+    #[test]
+    fn only_scoring_cards_add_chips() {
+        let played = [Card::new(King, Heart), Card::new(Two, Spade)];
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &Slate::new(5));
+
+        assert_eq!(score.hand_type(), HandType::HighCard);
+        assert_eq!(score.chips, Chips(5 + 10));
+    }
+
+    #[test]
+    fn enhancements_editions_and_seals() {
+        let played = [
+            Card::builder(Ace, Heart)
+                .enhancement(Enhancement::Bonus)
+                .edition(Edition::Foil(()))
+                .build(),
+            Card::builder(Ace, Spade)
+                .enhancement(Enhancement::Mult)
+                .edition(Edition::Holographic(()))
+                .seal(Seal::Gold)
+                .build(),
+            Card::builder(Ace, Club)
+                .enhancement(Enhancement::Glass)
+                .build(),
+        ];
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &Slate::new(5));
+
+        assert_eq!(score.hand_type(), HandType::ThreeOfAKind);
+        assert_eq!(score.chips, Chips(30 + 11 * 3 + 30 + 50));
+        assert_eq!(score.mult, Mult(((3 + 4 + 10) * 2) as f64));
+        assert_eq!(score.money, Money(3));
+    }
+
+    #[test]
+    fn polychrome_and_steel() {
+        let played = [Card::builder(Ace, Heart)
+            .edition(Edition::Polychrome(()))
+            .build()];
+        let held = [
+            Card::builder(Two, Heart)
+                .enhancement(Enhancement::Steel)
+                .build(),
+            Card::new(Three, Heart),
+            Card::builder(Four, Heart)
+                .enhancement(Enhancement::Steel)
+                .build(),
+        ];
+        let score = score_hand(&played, &held, &HandTypeStates::default(), &Slate::new(5));
+
+        assert_eq!(score.chips, Chips(16));
+        assert_eq!(score.mult, Mult(1.5 * 1.5 * 1.5));
+        assert_eq!(score.score(), Score::from(54));
+    }
+
+    #[test]
+    fn no_trace_by_default() {
+        let played = [Card::new(Ace, Heart)];
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &Slate::new(5));
+
+        assert!(score.trace.is_none());
+    }
+
+    #[test]
+    fn trace() {
+        let played = [
+            Card::new(Two, Club),
+            Card::builder(Ace, Heart)
+                .enhancement(Enhancement::Mult)
+                .seal(Seal::Gold)
+                .build(),
+        ];
+        let held = [Card::builder(Two, Heart)
+            .enhancement(Enhancement::Steel)
+            .build()];
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(SmearedJoker)).unwrap();
+        jokers.push(Joker::new(JimboJoker)).unwrap();
+
+        let score = Scorer::builder(&played, &held, &hands, &jokers)
+            .trace()
+            .build()
+            .score();
+
+        let steps = score.trace.unwrap();
+        let step = |source, reason, before: (u64, f64), after: (u64, f64), money| TraceStep {
+            source,
+            reason,
+            before: (Chips(before.0), Mult(before.1)),
+            after: (Chips(after.0), Mult(after.1)),
+            money: Money(money),
+        };
+
+        assert_eq!(
+            steps,
+            [
+                step(
+                    TraceSource::Played(1),
+                    TraceReason::Rank,
+                    (5, 1.0),
+                    (16, 1.0),
+                    0
+                ),
+                step(
+                    TraceSource::Played(1),
+                    TraceReason::Enhancement(Enhancement::Mult),
+                    (16, 1.0),
+                    (16, 5.0),
+                    0,
+                ),
+                step(
+                    TraceSource::Played(1),
+                    TraceReason::Seal(Seal::Gold),
+                    (16, 5.0),
+                    (16, 5.0),
+                    3,
+                ),
+                step(
+                    TraceSource::Held(0),
+                    TraceReason::Enhancement(Enhancement::Steel),
+                    (16, 5.0),
+                    (16, 7.5),
+                    0,
+                ),
+                step(
+                    TraceSource::Joker(1),
+                    TraceReason::Ability,
+                    (16, 7.5),
+                    (16, 11.5),
+                    0
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn red_seal_retriggers() {
+        let played = [Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Mult)
+            .seal(Seal::Red)
+            .build()];
+        let held = [Card::builder(Two, Heart)
+            .enhancement(Enhancement::Steel)
+            .seal(Seal::Red)
+            .build()];
+        let score = score_hand(&played, &held, &HandTypeStates::default(), &Slate::new(5));
+
+        assert_eq!(score.chips, Chips(5 + 11 * 2));
+        assert_eq!(score.mult, Mult((1.0 + 4.0 + 4.0) * 1.5 * 1.5));
+    }
+
+    #[test]
+    fn retriggers_stack() {
+        let played = [
+            Card::builder(King, Heart).seal(Seal::Red).build(),
+            Card::new(King, Spade),
+        ];
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(SockAndBuskinJoker)).unwrap();
+        jokers.push(Joker::new(HangingChadJoker)).unwrap();
+
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &jokers);
+
+        // the first king triggers 1 + 1 (seal) + 1 (sock) + 2 (chad) times, the second 1 + 1
+        assert_eq!(score.chips, Chips(10 + 10 * 5 + 10 * 2));
+    }
+
+    #[test]
+    fn retriggers_in_trace() {
+        let played = [Card::builder(Ace, Heart).seal(Seal::Red).build()];
+        let held = [Card::new(Two, Heart)];
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(MimeJoker)).unwrap();
+
+        let score = Scorer::builder(&played, &held, &hands, &jokers)
+            .trace()
+            .build()
+            .score();
+
+        let steps = score.trace.unwrap();
+        let reasons = steps.iter().map(|step| (step.source, step.reason));
+
+        assert_eq!(
+            reasons.collect::<Vec<_>>(),
+            [
+                (TraceSource::Played(0), TraceReason::Rank),
+                (TraceSource::Played(0), TraceReason::Retrigger),
+                (TraceSource::Played(0), TraceReason::Rank),
+            ]
+        );
+    }
+
+    #[test]
+    fn per_card_hooks_see_each_trigger() {
+        let played = [
+            Card::builder(Ace, Diamond).seal(Seal::Red).build(),
+            Card::new(Ace, Spade),
+        ];
+        let held = [Card::new(King, Club), Card::new(King, Heart)];
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(GreedyJoker)).unwrap();
+        jokers.push(Joker::new(BaronJoker)).unwrap();
+        jokers.push(Joker::new(MimeJoker)).unwrap();
+
+        let score = score_hand(&played, &held, &HandTypeStates::default(), &jokers);
+
+        assert_eq!(score.mult, Mult((2.0 + 3.0 + 3.0) * 1.5 * 1.5 * 1.5 * 1.5));
+    }
+
+    #[test]
+    fn jokers_after_cards() {
+        let played = [Card::new(Ace, Heart)];
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(JimboJoker)).unwrap();
+
+        let score = score_hand(&played, &[], &HandTypeStates::default(), &jokers);
+
+        assert_eq!(score.chips, Chips(5 + 11));
+        assert_eq!(score.mult, Mult(5.0));
+    }
+
+    #[test]
+    fn distribution() {
+        let played = [Card::new(Ace, Heart)];
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(MisprintJoker)).unwrap();
+
+        let dist = Scorer::builder(&played, &[], &hands, &jokers)
+            .build()
+            .distribution(100)
+            .unwrap();
+
+        assert_eq!(dist.outcomes().len(), 24);
+        assert_eq!(dist.min(), Score::from(16));
+        assert_eq!(dist.max(), Score::from(16 * 24));
+        assert_eq!(dist.mean(), Score::from(16 * 25 / 2));
+        assert_eq!(dist.probability_at_least(Score::from(16 * 13)), 0.5);
+    }
+
+    #[test]
+    fn distribution_of_rolls() {
+        let played = [Card::new(Two, Heart), Card::new(Two, Diamond)];
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(BloodstoneJoker)).unwrap();
+
+        let dist = Scorer::builder(&played, &[], &hands, &jokers)
+            .build()
+            .distribution(100)
+            .unwrap();
+
+        assert_eq!(
+            dist.outcomes(),
+            [(Score::from(14 * 2), 0.5), (Score::from(14 * 3), 0.5)]
+        );
+    }
+
+    #[test]
+    fn distribution_limit() {
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(MisprintJoker)).unwrap();
+        jokers.push(Joker::new(MisprintJoker)).unwrap();
+
+        let dist = Scorer::builder(&[], &[], &hands, &jokers)
+            .build()
+            .distribution(100);
+
+        assert_eq!(dist.unwrap_err(), TooManyOutcomes { limit: 100 });
+    }
+
+    #[test]
+    fn lucky() {
+        let played = [Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Lucky)
+            .build()];
+        let hands = HandTypeStates::default();
+
+        let dist = Scorer::builder(&played, &[], &hands, &Slate::new(5))
+            .build()
+            .distribution(100)
+            .unwrap();
+
+        let [(low, p_low), (high, p_high)] = dist.outcomes() else {
+            panic!("expected two outcomes");
+        };
+
+        assert_eq!((*low, *high), (Score::from(16), Score::from(16 * 21)));
+        assert!((p_low - 0.8).abs() < 1e-9 && (p_high - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn distribution_ignores_glass() {
+        let glass = Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Glass)
+            .build();
+        let played = [
+            glass.clone(),
+            glass.clone(),
+            glass.clone(),
+            glass.clone(),
+            glass,
+        ];
+        let hands = HandTypeStates::default();
+
+        let dist = Scorer::builder(&played, &[], &hands, &Slate::new(5))
+            .build()
+            .distribution(1)
+            .unwrap();
+
+        assert_eq!(dist.outcomes().len(), 1);
+    }
+
+    #[test]
+    fn distribution_of_lucky_money() {
+        let played = [Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Lucky)
+            .build()];
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(BullJoker)).unwrap();
+
+        let dist = Scorer::builder(&played, &[], &hands, &jokers)
+            .build()
+            .distribution(100)
+            .unwrap();
+
+        let scores = dist.outcomes().iter().map(|&(score, _)| score);
+        let chips = 5 + 11;
+        let expected = [chips, chips + 40, chips * 21, (chips + 40) * 21].map(Score::from);
+
+        assert!(scores.eq(expected));
+        assert!((dist.outcomes()[3].1 - 0.2 / 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn glass_shatters() {
+        let played = [Card::builder(Ace, Heart)
+            .enhancement(Enhancement::Glass)
+            .build()];
+        let hands = HandTypeStates::default();
+        let jokers = Slate::new(5);
+
+        let destroyed = (0..32).map(|seed| {
+            Scorer::builder(&played, &[], &hands, &jokers)
+                .rng(RunRng::new(seed))
+                .build()
+                .score()
+                .destroyed
+        });
+        let destroyed = destroyed.collect::<Vec<_>>();
+
+        assert!(destroyed.iter().any(|d| d.is_empty()));
+        assert!(destroyed.iter().any(|d| d == &[0]));
+    }
+
+    #[test]
+    fn joker_editions() {
+        let hands = HandTypeStates::default();
+        let mut jokers = Slate::new(5);
+
+        jokers
+            .push(
+                Joker::builder(JimboJoker)
+                    .edition(Edition::Polychrome(()))
+                    .build(),
+            )
+            .unwrap();
+        jokers
+            .push(
+                Joker::builder(StencilJoker)
+                    .edition(Edition::Holographic(()))
+                    .build(),
+            )
+            .unwrap();
+        jokers
+            .push(
+                Joker::builder(BlueprintJoker)
+                    .edition(Edition::Foil(()))
+                    .build(),
+            )
+            .unwrap();
+
+        let score = Scorer::builder(&[], &[], &hands, &jokers)
+            .trace()
+            .build()
+            .score();
+
+        // the foil blueprint has nothing to its right to copy, but still adds chips
+        assert_eq!(score.chips, Chips(5 + 50));
+        assert_eq!(score.mult, Mult(((1.0 + 4.0) * 1.5 + 10.0) * 3.0));
+
+        let steps = score.trace.unwrap();
+        let reasons = steps.iter().map(|step| (step.source, step.reason));
+
+        assert_eq!(
+            reasons.collect::<Vec<_>>(),
+            [
+                (TraceSource::Joker(0), TraceReason::Ability),
+                (TraceSource::Joker(0), TraceReason::Edition),
+                (TraceSource::Joker(1), TraceReason::Edition),
+                (TraceSource::Joker(1), TraceReason::Ability),
+                (TraceSource::Joker(2), TraceReason::Edition),
+            ]
+        );
+    }
 }
@@ -4,6 +4,14 @@ use crate::Slate;
 pub trait JokerSlateExt {
     fn kind<J: JokerKind>(&self) -> impl Iterator<Item = Joker>;
     fn has_kind<J: JokerKind>(&self) -> bool;
+
+    /// The jokers whose abilities apply, skipping debuffed ones.
+    fn active(&self) -> impl Iterator<Item = &Joker>;
+
+    /// The joker whose ability the joker at `index` uses, following copiers like
+    /// Blueprint. `None` if it copies nothing, something incompatible, or a loop, or if
+    /// any joker along the way is debuffed.
+    fn ability(&self, index: usize) -> Option<Joker>;
 }
 
 impl JokerSlateExt for Slate<Joker> {
@@ -14,6 +22,78 @@ impl JokerSlateExt for Slate<Joker> {
     fn has_kind<J: JokerKind>(&self) -> bool {
         self.kind::<J>().next().is_some()
     }
+
+    fn active(&self) -> impl Iterator<Item = &Joker> {
+        self.iter().filter(|joker| !joker.is_debuffed())
+    }
+
+    fn ability(&self, mut index: usize) -> Option<Joker> {
+        let mut joker = self.get(index).filter(|j| !j.is_debuffed())?;
+
+        for _ in 0..self.len() {
+            let Some(target) = joker.kind().copies(self, index) else {
+                return Some(joker.clone());
+            };
+
+            index = target;
+            joker = self
+                .get(index)
+                .filter(|j| j.kind().blueprint_compatible() && !j.is_debuffed())?;
+        }
+
+        None
+    }
+}
+
+/// Runs a hook for each joker in slot order, writing back any state the joker changed
+/// and removing it if that left it spent.
+///
+/// Hooks are free to add or remove jokers; the joker being run is tracked by identity
+/// rather than by index, and jokers removed before their turn are skipped, as are
+/// debuffed ones. Copiers run the hook on a throwaway clone of their target, so they
+/// see its state but can't change it.
+pub(crate) fn each_joker<C>(
+    ctx: &mut C,
+    jokers: fn(&mut C) -> &mut Slate<Joker>,
+    mut f: impl FnMut(usize, &mut dyn JokerKind, &mut C),
+) {
+    for (index, original) in jokers(ctx).clone().into_iter().enumerate() {
+        let Some(index) = joker_position(jokers(ctx), &original, index) else {
+            continue;
+        };
+
+        // as it is now, since an earlier hook may have changed it
+        let mut joker = jokers(ctx).get(index).cloned().expect("joker at position");
+
+        if joker.is_debuffed() {
+            continue;
+        }
+
+        match jokers(ctx).ability(index) {
+            Some(mut ability) if !ability.same(&joker) => f(index, ability.kind_mut(), ctx),
+            // A copier with nothing to copy runs its own hooks, which do nothing.
+            _ => f(index, joker.kind_mut(), ctx),
+        }
+
+        // only the kind, since the hook may have changed the joker itself through `ctx`
+        if let Some(index) = joker_position(jokers(ctx), &original, index) {
+            let slot = jokers(ctx).get_mut(index).expect("joker at position");
+            slot.kind = joker.kind;
+
+            if slot.kind.is_spent() {
+                jokers(ctx).remove(index);
+            }
+        }
+    }
+}
+
+/// Where `joker` is in `jokers`, checking `hint` first so that clones of one joker in
+/// several slots are told apart by where they were.
+pub(crate) fn joker_position(jokers: &Slate<Joker>, joker: &Joker, hint: usize) -> Option<usize> {
+    match jokers.get(hint) {
+        Some(j) if j.same(joker) => Some(hint),
+        _ => jokers.iter().position(|j| j.same(joker)),
+    }
 }
 
 #[cfg(test)]
@@ -21,6 +101,16 @@ mod tests {
     use super::super::*;
     use super::*;
 
+    #[derive(Debug, Clone, Default, JokerInfo)]
+    #[joker(id = "test_egg_peeker", name = "Egg Peeker", rarity = Common, price = 1)]
+    struct EggPeekerJoker;
+
+    impl JokerKind for EggPeekerJoker {
+        fn on_round_end(&mut self, run: &mut Run) {
+            run.jokers.get_mut(1).unwrap().downcast_mut::<EggJoker>();
+        }
+    }
+
     #[test]
     fn finding_jokers_by_type_id() {
         let mut slate = Slate::<Joker>::new(1);
@@ -33,4 +123,41 @@ mod tests {
         assert!(!slate.has_kind::<StencilJoker>());
         assert_eq!(slate.kind::<StencilJoker>().collect::<Vec<_>>().len(), 0);
     }
+
+    #[test]
+    fn state_is_copy_on_write() {
+        let mut slate = Slate::<Joker>::new(1);
+
+        slate.push(Joker::new(GreenJoker::default())).unwrap();
+
+        let snapshot = slate.clone();
+        let green = slate.get_mut(0).unwrap().downcast_mut::<GreenJoker>();
+        green.unwrap().mult = 3;
+
+        let mult = |slate: &Slate<Joker>| {
+            slate
+                .get(0)
+                .unwrap()
+                .downcast_ref::<GreenJoker>()
+                .unwrap()
+                .mult
+        };
+
+        assert_eq!(mult(&slate), 3);
+        assert_eq!(mult(&snapshot), 0);
+    }
+
+    #[test]
+    fn hooks_survive_earlier_copy_on_write() {
+        let mut jokers = Slate::new(5);
+
+        jokers.push(Joker::new(EggPeekerJoker)).unwrap();
+        jokers.push(Joker::new(EggJoker::default())).unwrap();
+
+        let mut run = Run::new(jokers, Money(0));
+        run.end_round(&[]);
+
+        let egg = run.jokers.get(1).unwrap().downcast_ref::<EggJoker>();
+        assert_eq!(egg.unwrap().value, Money(3));
+    }
 }
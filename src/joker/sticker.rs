@@ -0,0 +1,43 @@
+/// How many rounds a Perishable joker lasts before it's debuffed.
+pub const PERISHABLE_ROUNDS: u8 = 5;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sticker {
+    /// Can't be sold or destroyed.
+    Eternal,
+    /// Debuffed after [`PERISHABLE_ROUNDS`] rounds.
+    Perishable,
+    /// Costs $1 to buy, but $3 at the end of each round.
+    Rental,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Stickers {
+    pub eternal: bool,
+    /// Rounds left until the joker is debuffed, if it's Perishable.
+    pub perishable: Option<u8>,
+    pub rental: bool,
+}
+
+impl Stickers {
+    pub fn has(self, sticker: Sticker) -> bool {
+        match sticker {
+            Sticker::Eternal => self.eternal,
+            Sticker::Perishable => self.perishable.is_some(),
+            Sticker::Rental => self.rental,
+        }
+    }
+
+    pub(crate) fn add(&mut self, sticker: Sticker) {
+        match sticker {
+            Sticker::Eternal => self.eternal = true,
+            Sticker::Perishable => self.perishable = Some(PERISHABLE_ROUNDS),
+            Sticker::Rental => self.rental = true,
+        }
+    }
+
+    /// A Perishable joker with no rounds left.
+    pub fn is_debuffed(self) -> bool {
+        self.perishable == Some(0)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{Chips, Enhancement, Money, Mult, Seal};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    pub source: TraceSource,
+    pub reason: TraceReason,
+    pub before: (Chips, Mult),
+    pub after: (Chips, Mult),
+    pub money: Money,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TraceSource {
+    /// Index into the played cards.
+    Played(usize),
+    /// Index into the cards held in hand.
+    Held(usize),
+    /// Index into the joker slate.
+    Joker(usize),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TraceReason {
+    Rank,
+    Enhancement(Enhancement),
+    Edition,
+    Seal(Seal),
+    /// The card is about to trigger again, from a Red Seal or a joker.
+    Retrigger,
+    Ability,
+}
@@ -1,26 +1,44 @@
+// Lets `balatro-macros` refer to `::balatro` from inside this crate too.
+extern crate self as balatro;
+
+#[doc(hidden)]
+pub use inventory;
+
 mod ante;
 mod blind;
 mod card;
+mod chance;
+mod consumable;
 mod edition;
 mod hand;
 mod joker;
 mod money;
 mod planet;
 mod rank;
+mod rng;
+mod run;
 mod score;
+mod stake;
 mod suit;
+mod tarot;
 
 pub use ante::*;
 pub use blind::*;
 pub use card::*;
+pub use chance::*;
+pub use consumable::*;
 pub use edition::*;
 pub use hand::*;
 pub use joker::*;
 pub use money::*;
 pub use planet::*;
 pub use rank::*;
+pub use rng::*;
+pub use run::*;
 pub use score::*;
+pub use stake::*;
 pub use suit::*;
+pub use tarot::*;
 
 mod sealed {
     pub trait Sealed {}
@@ -32,6 +32,11 @@ impl Planet {
         HandType::variants().map(Self)
     }
 
+    /// The planet that levels up `hand_type`.
+    pub const fn for_hand_type(hand_type: HandType) -> Self {
+        Self(hand_type)
+    }
+
     pub const fn hand_type(self) -> HandType {
         self.0
     }
@@ -0,0 +1,555 @@
+use crate::{
+    Ante, Blind, Card, Chance, Consumable, Enhancement, HandScore, HandType, HandTypeStates, Joker,
+    JokerKind, JokerSlateExt, Money, MoneyError, Planet, Probabilities, RunRng, Scorer, Seal,
+    Slate, Tarot, each_joker, joker_position,
+};
+use rand::Rng;
+use thiserror::Error;
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Run {
+    pub jokers: Slate<Joker>,
+    pub hands: HandTypeStates,
+    pub money: Money,
+    /// The lowest that spending can take money, before jokers like Credit Card.
+    pub debt_floor: Money,
+    pub rng: RunRng,
+    pub consumables: Slate<Consumable>,
+    /// Hands given at the start of each round.
+    pub round_hands: u8,
+    /// Discards given at the start of each round.
+    pub round_discards: u8,
+    pub hands_left: u8,
+    pub discards_left: u8,
+    /// The type of the last hand played, for Blue Seals.
+    pub last_hand: Option<HandType>,
+    pub ante: Ante,
+    /// Beating the boss blind of this ante wins the run.
+    pub win_ante: Ante,
+    pub state: RunState,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
+pub enum TransactionError {
+    #[error("no joker at index {0}")]
+    NoJoker(usize),
+    #[error("no free joker slots")]
+    SlotsFull,
+    #[error("eternal jokers can't be sold")]
+    Eternal,
+    #[error(transparent)]
+    Money(#[from] MoneyError),
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum RunState {
+    #[default]
+    Playing,
+    /// The win ante's boss was beaten, and the run can go on in endless mode.
+    Won,
+    Endless,
+}
+
+impl Run {
+    pub fn new(jokers: Slate<Joker>, money: Money) -> Self {
+        Self::seeded(jokers, money, 0)
+    }
+
+    pub fn seeded(jokers: Slate<Joker>, money: Money, seed: u64) -> Self {
+        Self {
+            jokers,
+            hands: HandTypeStates::default(),
+            money,
+            debt_floor: Money::ZERO,
+            rng: RunRng::new(seed),
+            consumables: Slate::new(2),
+            round_hands: 4,
+            round_discards: 3,
+            hands_left: 4,
+            discards_left: 3,
+            last_hand: None,
+            ante: Ante::default(),
+            win_ante: Ante::WIN,
+            state: RunState::Playing,
+        }
+    }
+
+    pub fn play(&mut self, played: &[Card], held: &[Card]) -> HandScore {
+        let mut scorer = Scorer::builder(played, held, &self.hands, &self.jokers)
+            .balance(self.money)
+            .rng(self.rng.clone());
+
+        if self.hands_left <= 1 {
+            scorer = scorer.final_hand();
+        }
+
+        let score = scorer.build().score();
+
+        self.hands_left = self.hands_left.saturating_sub(1);
+
+        self.hands = self.hands.plays_up(score.hand_type());
+        self.last_hand = Some(score.hand_type());
+        self.money += score.money;
+        self.jokers = score.jokers.clone();
+        self.rng = score.rng.clone();
+
+        if !score.destroyed.is_empty() {
+            let destroyed = score.destroyed.iter().map(|&index| played[index].clone());
+            self.destroy_cards(&destroyed.collect::<Vec<_>>());
+        }
+
+        score
+    }
+
+    pub fn discard(&mut self, discarded: &[Card]) {
+        self.discards_left = self.discards_left.saturating_sub(1);
+
+        for card in discarded {
+            if card.seal == Some(Seal::Purple) {
+                let stream = self.rng.stream("purple_seal");
+                let tarot = Tarot::variants().nth(stream.random_range(0..Tarot::VARIANT_COUNT));
+
+                self.create_consumable(tarot.expect("tarot in range").into());
+            }
+        }
+
+        self.each_joker(|joker, run| joker.on_discard(run, discarded));
+    }
+
+    /// Starts a round, with a fresh set of hands and discards.
+    pub fn select_blind(&mut self, blind: Blind) {
+        self.hands_left = self.round_hands;
+        self.discards_left = self.round_discards;
+
+        self.each_joker(|joker, run| joker.on_blind_selected(run, blind));
+    }
+
+    pub fn end_round(&mut self, held: &[Card]) {
+        let scorer = Scorer::new(self.jokers.clone(), &[], held, &self.hands);
+        let gold = held
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| card.enhancement == Some(Enhancement::Gold))
+            .map(|(index, _)| 1 + scorer.held_retriggers(index));
+        self.money += Money(3) * gold.sum::<usize>() as i64;
+
+        if let Some(hand_type) = self.last_hand {
+            let blue = held.iter().filter(|card| card.seal == Some(Seal::Blue));
+
+            for _ in 0..blue.count() {
+                self.create_consumable(Planet::for_hand_type(hand_type).into());
+            }
+        }
+
+        let rent = self.jokers.iter_mut().map(Joker::end_round).sum();
+        self.money -= rent;
+
+        self.each_joker(|joker, run| joker.on_round_end(run));
+    }
+
+    /// How much can be spent before hitting the debt floor.
+    pub fn available(&self) -> Money {
+        let debt = self.jokers.active().map(|joker| joker.kind().debt());
+        let floor = self.debt_floor - debt.sum();
+
+        (self.money - floor).max(Money::ZERO)
+    }
+
+    pub fn spend(&mut self, cost: Money) -> Result<(), MoneyError> {
+        let available = self.available();
+
+        if cost > available {
+            return Err(MoneyError::CantAfford { cost, available });
+        }
+
+        self.money -= cost;
+        Ok(())
+    }
+
+    /// Moves on to the next ante, winning the run if this was the win ante's boss.
+    pub fn defeat_boss(&mut self) -> RunState {
+        if self.state == RunState::Playing && self.ante >= self.win_ante {
+            self.state = RunState::Won;
+        }
+
+        self.ante = self.ante.next();
+        self.state
+    }
+
+    /// Keeps playing after a win. Does nothing unless the run has just been won.
+    pub fn continue_endless(&mut self) -> bool {
+        let won = self.state == RunState::Won;
+
+        if won {
+            self.state = RunState::Endless;
+        }
+
+        won
+    }
+
+    /// Pays the joker's price and adds it to the slate, or does neither.
+    pub fn buy_joker(&mut self, joker: Joker) -> Result<(), TransactionError> {
+        if !self.jokers.fits(&joker) {
+            return Err(TransactionError::SlotsFull);
+        }
+
+        self.spend(joker.price())?;
+        self.jokers.push(joker).expect("joker to fit");
+
+        Ok(())
+    }
+
+    /// Removes the joker and pays out its sell value, as it is after its sell hook.
+    pub fn sell_joker(&mut self, index: usize) -> Result<Joker, TransactionError> {
+        let original = self
+            .jokers
+            .get(index)
+            .ok_or(TransactionError::NoJoker(index))?
+            .clone();
+
+        if original.stickers().eternal {
+            return Err(TransactionError::Eternal);
+        }
+
+        let mut joker = original.clone();
+        joker.kind_mut().on_sell(self);
+
+        if let Some(index) = joker_position(&self.jokers, &original, index) {
+            self.jokers.remove(index);
+        }
+
+        self.money += joker.sell_value();
+        Ok(joker)
+    }
+
+    /// Eternal jokers can't be destroyed.
+    pub fn destroy_joker(&mut self, index: usize) -> Option<Joker> {
+        self.jokers.get(index).filter(|j| !j.stickers().eternal)?;
+        Some(self.jokers.remove(index))
+    }
+
+    pub fn reroll_shop(&mut self) {
+        self.each_joker(|joker, run| joker.on_reroll(run));
+    }
+
+    pub fn destroy_cards(&mut self, destroyed: &[Card]) {
+        self.each_joker(|joker, run| joker.on_cards_destroyed(run, destroyed));
+    }
+
+    pub fn add_cards(&mut self, added: &[Card]) {
+        self.each_joker(|joker, run| joker.on_cards_added(run, added));
+    }
+
+    /// Rolls the listed `chance` on the `key` stream, scaled by the current jokers.
+    pub fn roll(&mut self, key: &'static str, chance: Chance) -> bool {
+        let chance = Probabilities::new(&self.jokers).scale(chance);
+        chance.roll(self.rng.stream(key))
+    }
+
+    /// Adds a consumable if there's a free slot, returning whether it was added.
+    pub fn create_consumable(&mut self, consumable: Consumable) -> bool {
+        self.consumables.push(consumable).is_ok()
+    }
+
+    fn each_joker(&mut self, mut f: impl FnMut(&mut dyn JokerKind, &mut Self)) {
+        each_joker(self, |run| &mut run.jokers, |_, kind, run| f(kind, run));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Chips, ConsumableKind, CreditCardJoker, DuskJoker, Edition, FacelessJoker, GreenJoker,
+        IceCreamJoker, JimboJoker, MimeJoker, MisprintJoker, Mult, PERISHABLE_ROUNDS, Rank::*,
+        Sticker, Suit::*,
+    };
+
+    fn run(jokers: impl IntoIterator<Item = Joker>) -> Run {
+        let mut slate = Slate::new(5);
+
+        for joker in jokers {
+            slate.push(joker).unwrap();
+        }
+
+        Run::new(slate, Money(4))
+    }
+
+    #[test]
+    fn play() {
+        let mut run = run([]);
+        let played = [Card::builder(Ace, Heart).seal(Seal::Gold).build()];
+
+        let score = run.play(&played, &[]);
+
+        assert_eq!(score.hand_type(), HandType::HighCard);
+        assert_eq!(run.hands.high_card().plays(), 1);
+        assert_eq!(run.money, Money(7));
+    }
+
+    #[test]
+    fn discard() {
+        let mut run = run([Joker::new(FacelessJoker)]);
+
+        run.discard(&[Card::new(King, Heart), Card::new(Queen, Heart)]);
+        assert_eq!(run.money, Money(4));
+
+        run.discard(&[
+            Card::new(King, Heart),
+            Card::new(Queen, Heart),
+            Card::new(Jack, Heart),
+        ]);
+        assert_eq!(run.money, Money(9));
+    }
+
+    #[test]
+    fn sell_joker() {
+        let mut run = run([Joker::new(JimboJoker)]);
+
+        assert_eq!(run.sell_joker(1).unwrap_err(), TransactionError::NoJoker(1));
+        assert!(run.sell_joker(0).unwrap().is::<JimboJoker>());
+        assert!(run.jokers.is_empty());
+        assert_eq!(run.money, Money(4 + 1));
+    }
+
+    #[test]
+    fn sell_cloned_joker() {
+        let green = Joker::new(GreenJoker::default());
+        let mut run = run([green.clone(), Joker::new(JimboJoker), green]);
+
+        run.sell_joker(2).unwrap();
+
+        let names = run.jokers.iter().map(Joker::name).collect::<Vec<_>>();
+        assert_eq!(names, ["Green Joker", "Joker"]);
+    }
+
+    #[test]
+    fn same_seed_same_run() {
+        let play = |seed| {
+            let mut run = Run::seeded(run([Joker::new(MisprintJoker)]).jokers, Money(0), seed);
+            let played = [Card::new(Ace, Heart)];
+
+            [run.play(&played, &[]).mult, run.play(&played, &[]).mult]
+        };
+
+        assert_eq!(play(1234), play(1234));
+    }
+
+    #[test]
+    fn final_hand() {
+        let mut run = run([Joker::new(DuskJoker)]);
+        let played = [Card::new(Ace, Heart)];
+
+        run.select_blind(Blind::Small);
+
+        for _ in 1..run.round_hands {
+            assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11));
+        }
+
+        assert_eq!(run.hands_left, 1);
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11 * 2));
+
+        run.select_blind(Blind::Big);
+        assert_eq!(run.play(&played, &[]).chips, Chips(5 + 11));
+    }
+
+    #[test]
+    fn gold_cards_held_at_end_of_round() {
+        let mut run = run([]);
+        let gold = Card::builder(King, Heart)
+            .enhancement(Enhancement::Gold)
+            .build();
+
+        run.end_round(&[gold.clone(), gold, Card::new(Two, Heart)]);
+        assert_eq!(run.money, Money(4 + 3 * 2));
+    }
+
+    #[test]
+    fn gold_cards_retrigger() {
+        let mut run = run([Joker::new(MimeJoker)]);
+        let gold = Card::builder(King, Heart)
+            .enhancement(Enhancement::Gold)
+            .seal(Seal::Red)
+            .build();
+
+        run.end_round(&[gold]);
+        assert_eq!(run.money, Money(4 + 3 * 3));
+    }
+
+    #[test]
+    fn blue_seal() {
+        let mut run = run([]);
+        let blue = Card::builder(Two, Spade).seal(Seal::Blue).build();
+
+        run.play(&[Card::new(Ace, Heart), Card::new(Ace, Spade)], &[]);
+        run.end_round(&[blue.clone(), blue.clone(), blue]);
+
+        let planets = run.consumables.iter().map(|c| c.kind);
+        assert_eq!(
+            planets.collect::<Vec<_>>(),
+            [ConsumableKind::Planet(Planet::Mercury); 2]
+        );
+    }
+
+    #[test]
+    fn purple_seal() {
+        let mut run = run([]);
+        let purple = Card::builder(Two, Spade).seal(Seal::Purple).build();
+
+        run.discard(&[purple.clone(), Card::new(Three, Spade)]);
+        assert_eq!(run.consumables.len(), 1);
+        assert!(matches!(
+            run.consumables.get(0).unwrap().kind,
+            ConsumableKind::Tarot(_)
+        ));
+
+        run.discard(&[purple.clone(), purple.clone(), purple]);
+        assert!(run.consumables.is_full());
+    }
+
+    #[test]
+    fn win_and_endless() {
+        let mut run = run([]);
+        run.win_ante = Ante::new(2);
+
+        assert!(!run.continue_endless());
+        assert_eq!(run.defeat_boss(), RunState::Playing);
+        assert_eq!(run.defeat_boss(), RunState::Won);
+        assert_eq!(run.ante, Ante::new(3));
+
+        assert!(run.continue_endless());
+        assert_eq!(run.defeat_boss(), RunState::Endless);
+        assert_eq!(run.ante, Ante::new(4));
+    }
+
+    #[test]
+    fn ante_decreases() {
+        let mut run = run([]);
+
+        run.ante = run.ante.prev().prev();
+        assert_eq!(run.ante, Ante::new(-1));
+
+        assert_eq!(run.defeat_boss(), RunState::Playing);
+        assert_eq!(run.ante, Ante::new(0));
+    }
+
+    #[test]
+    fn spend() {
+        let mut run = run([]);
+
+        assert_eq!(run.spend(Money(3)), Ok(()));
+        assert_eq!(
+            run.spend(Money(2)),
+            Err(MoneyError::CantAfford {
+                cost: Money(2),
+                available: Money(1),
+            })
+        );
+        assert_eq!(run.money, Money(1));
+    }
+
+    #[test]
+    fn spend_into_debt() {
+        let mut run = run([Joker::new(CreditCardJoker)]);
+
+        assert_eq!(run.available(), Money(24));
+        assert_eq!(run.spend(Money(10)), Ok(()));
+        assert_eq!(run.money, Money(-6));
+
+        run.debt_floor = Money(-5);
+        assert_eq!(run.available(), Money(19));
+    }
+
+    #[test]
+    fn debuffed_credit_card() {
+        let credit_card = Joker::builder(CreditCardJoker)
+            .sticker(Sticker::Perishable)
+            .build();
+        let mut run = run([credit_card]);
+
+        for _ in 0..PERISHABLE_ROUNDS {
+            run.end_round(&[]);
+        }
+
+        assert_eq!(run.available(), Money(4));
+    }
+
+    #[test]
+    fn eternal() {
+        let eternal = Joker::builder(JimboJoker).sticker(Sticker::Eternal).build();
+        let mut run = run([eternal]);
+
+        assert_eq!(run.sell_joker(0).unwrap_err(), TransactionError::Eternal);
+        assert!(run.destroy_joker(0).is_none());
+        assert_eq!(run.jokers.len(), 1);
+    }
+
+    #[test]
+    fn perishable() {
+        let perishable = Joker::builder(JimboJoker)
+            .sticker(Sticker::Perishable)
+            .build();
+        let mut run = run([perishable]);
+        let played = [Card::new(Ace, Heart)];
+
+        for _ in 0..PERISHABLE_ROUNDS {
+            assert_eq!(run.play(&played, &[]).mult, Mult(1.0 + 4.0));
+            run.end_round(&[]);
+        }
+
+        assert!(run.jokers.get(0).unwrap().is_debuffed());
+        assert_eq!(run.play(&played, &[]).mult, Mult(1.0));
+    }
+
+    #[test]
+    fn rental() {
+        let rental = Joker::builder(JimboJoker).sticker(Sticker::Rental).build();
+        let mut run = run([rental]);
+
+        assert_eq!(run.jokers.get(0).unwrap().price(), Money(1));
+
+        run.end_round(&[]);
+        assert_eq!(run.money, Money(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn incompatible_sticker() {
+        Joker::builder(IceCreamJoker::default()).sticker(Sticker::Eternal);
+    }
+
+    #[test]
+    fn buy_joker() {
+        let mut run = run([]);
+        run.jokers = Slate::new(1);
+
+        let poly = Joker::builder(JimboJoker)
+            .edition(Edition::Polychrome(()))
+            .build();
+        assert_eq!(
+            run.buy_joker(poly),
+            Err(TransactionError::Money(MoneyError::CantAfford {
+                cost: Money(2 + 5),
+                available: Money(4),
+            }))
+        );
+        assert!(run.jokers.is_empty());
+
+        run.buy_joker(Joker::new(JimboJoker)).unwrap();
+        assert_eq!(run.money, Money(2));
+
+        let err = run.buy_joker(Joker::new(JimboJoker)).unwrap_err();
+        assert_eq!(err, TransactionError::SlotsFull);
+        assert_eq!(run.money, Money(2));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn runs_cross_threads() {
+        let run = run([Joker::new(IceCreamJoker::default())]);
+        let run = std::thread::spawn(move || run).join().unwrap();
+
+        assert_eq!(run.jokers.len(), 1);
+    }
+}
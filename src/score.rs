@@ -1,15 +1,25 @@
 use paste::paste;
-use std::ops::{Add, AddAssign, Mul, MulAssign};
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Mul, MulAssign},
+};
 
 // TODO: Consider making the u64 private, `ChipsAllowMul` is toothless otherwise.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Chips(pub u64);
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Mult(pub u64);
+// Mult is fractional once any xMult has been applied. The game stores it as a
+// double and multiplies in the same order we do, so `f64` matches it exactly
+// rather than approximating it, including across long chains of xMult.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Mult(pub f64);
 
 macro_rules! op {
     ($trait:ident::$method:ident for $newtype:ident) => {
+        op!($trait::$method for $newtype, u64 => u64);
+    };
+    ($trait:ident::$method:ident for $newtype:ident, $($rhs:ty => $inner:ty),*) => {
         impl $trait for $newtype {
             type Output = $newtype;
 
@@ -18,13 +28,15 @@ macro_rules! op {
             }
         }
 
-        impl $trait<u64> for $newtype {
-            type Output = $newtype;
+        $(
+            impl $trait<$rhs> for $newtype {
+                type Output = $newtype;
 
-            fn $method(self, rhs: u64) -> $newtype {
-                $newtype(self.0.$method(rhs))
+                fn $method(self, rhs: $rhs) -> $newtype {
+                    $newtype(self.0.$method(rhs as $inner))
+                }
             }
-        }
+        )*
 
         paste! {
             impl [<$trait Assign>] for $newtype {
@@ -33,18 +45,21 @@ macro_rules! op {
                 }
             }
 
-            impl [<$trait Assign>]<u64> for $newtype {
-                fn [<$method _assign>](&mut self, rhs: u64) {
-                    *self = Self(self.0.$method(rhs))
+            $(
+                impl [<$trait Assign>]<$rhs> for $newtype {
+                    fn [<$method _assign>](&mut self, rhs: $rhs) {
+                        *self = Self(self.0.$method(rhs as $inner))
+                    }
                 }
-            }
+            )*
         }
     };
 }
 
 op!(Add::add for Chips);
-op!(Add::add for Mult);
-op!(Mul::mul for Mult);
+op!(Add::add for Mult, u64 => f64);
+// multiplying by an `f64` is xMult, e.g. `mult *= 1.5` for Polychrome
+op!(Mul::mul for Mult, u64 => f64, f64 => f64);
 
 // needed for some calculations like calculating planet scaling
 // but wrapped in a separate newtype so it can't be done by accident
@@ -63,3 +78,374 @@ impl ChipsAllowMul {
         Chips(self.0)
     }
 }
+
+/// The final score for a hand, which the game rounds down.
+impl Mul<Mult> for Chips {
+    type Output = Score;
+
+    fn mul(self, rhs: Mult) -> Score {
+        (Score::from(self) * Score::from_f64(rhs.0)).floor()
+    }
+}
+
+/// A score that can grow far past `u64` and `f64`, as it does in late endless antes.
+///
+/// Stored as `mantissa * 2^exponent` with the mantissa in `[1, 2)`. Scaling by powers
+/// of two is exact, so anything a `f64` can hold exactly is held exactly here too.
+#[derive(Debug, Copy, Clone)]
+pub struct Score {
+    mantissa: f64,
+    exponent: i64,
+}
+
+impl Score {
+    pub const ZERO: Self = Self {
+        mantissa: 1.0,
+        exponent: i64::MIN,
+    };
+
+    /// What the game displays as "naneinf", once its own doubles have overflowed.
+    pub const INFINITY: Self = Self {
+        mantissa: 1.0,
+        exponent: i64::MAX,
+    };
+
+    // the game switches to scientific notation at this point
+    const SCIENTIFIC_FROM: f64 = 1e11;
+
+    pub fn from_f64(value: f64) -> Self {
+        Self::normalize(value, 0)
+    }
+
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    pub fn is_infinite(self) -> bool {
+        self == Self::INFINITY
+    }
+
+    /// Lossy once the score is too big for a `f64`, where it becomes infinite.
+    pub fn to_f64(self) -> f64 {
+        // beyond these a `f64` is all zero or all infinity
+        match self.exponent {
+            ..-1100 => 0.0,
+            1100.. => f64::INFINITY,
+            exponent => self.mantissa * 2f64.powi(exponent as i32),
+        }
+    }
+
+    pub fn log10(self) -> f64 {
+        self.log2() * std::f64::consts::LOG10_2
+    }
+
+    fn log2(self) -> f64 {
+        match self.exponent {
+            i64::MIN => f64::NEG_INFINITY,
+            i64::MAX => f64::INFINITY,
+            exponent => exponent as f64 + self.mantissa.log2(),
+        }
+    }
+
+    pub fn powf(self, exp: f64) -> Self {
+        Self::from_log2(self.log2() * exp)
+    }
+
+    pub fn floor(self) -> Self {
+        if self.exponent < f64::MANTISSA_DIGITS as i64 {
+            Self::from_f64(self.to_f64().floor())
+        } else {
+            self
+        }
+    }
+
+    fn from_log2(log2: f64) -> Self {
+        if log2 == f64::NEG_INFINITY {
+            Self::ZERO
+        } else if !log2.is_finite() || log2 >= i64::MAX as f64 {
+            Self::INFINITY
+        } else {
+            let exponent = log2.floor();
+            Self::normalize((log2 - exponent).exp2(), exponent as i64)
+        }
+    }
+
+    fn normalize(mantissa: f64, exponent: i64) -> Self {
+        if mantissa <= 0.0 {
+            return Self::ZERO;
+        }
+
+        if !mantissa.is_finite() {
+            return Self::INFINITY;
+        }
+
+        const EXPONENT_BITS: u64 = 0x7ff << 52;
+        const BIAS: i64 = 1023;
+
+        // subnormals don't carry their scale in the exponent bits, so bump them up first
+        let (mantissa, exponent) = if mantissa < f64::MIN_POSITIVE {
+            (mantissa * 2f64.powi(64), exponent.saturating_sub(64))
+        } else {
+            (mantissa, exponent)
+        };
+
+        let bits = mantissa.to_bits();
+        let shift = ((bits & EXPONENT_BITS) >> 52) as i64 - BIAS;
+
+        match exponent.checked_add(shift) {
+            Some(i64::MIN) | None if shift < 0 => Self::ZERO,
+            Some(i64::MAX) | None => Self::INFINITY,
+            Some(exponent) => Self {
+                mantissa: f64::from_bits((bits & !EXPONENT_BITS) | ((BIAS as u64) << 52)),
+                exponent,
+            },
+        }
+    }
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<u64> for Score {
+    fn from(value: u64) -> Self {
+        Self::from_f64(value as f64)
+    }
+}
+
+impl From<Chips> for Score {
+    fn from(chips: Chips) -> Self {
+        Self::from(chips.0)
+    }
+}
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Score {}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Score {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.exponent
+            .cmp(&other.exponent)
+            .then(self.mantissa.total_cmp(&other.mantissa))
+    }
+}
+
+impl Add for Score {
+    type Output = Score;
+
+    fn add(self, rhs: Score) -> Score {
+        let (big, small) = if self >= rhs {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        if big.is_infinite() || small.is_zero() {
+            return big;
+        }
+
+        match big.exponent - small.exponent {
+            // too small to affect the mantissa at all
+            gap if gap > f64::MANTISSA_DIGITS as i64 => big,
+            gap => Self::normalize(
+                big.mantissa + small.mantissa * 2f64.powi(-gap as i32),
+                big.exponent,
+            ),
+        }
+    }
+}
+
+impl AddAssign for Score {
+    fn add_assign(&mut self, rhs: Score) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul for Score {
+    type Output = Score;
+
+    fn mul(self, rhs: Score) -> Score {
+        if self.is_zero() || rhs.is_zero() {
+            Self::ZERO
+        } else if self.is_infinite() || rhs.is_infinite() {
+            Self::INFINITY
+        } else {
+            Self::normalize(
+                self.mantissa * rhs.mantissa,
+                self.exponent.saturating_add(rhs.exponent),
+            )
+        }
+    }
+}
+
+impl Mul<f64> for Score {
+    type Output = Score;
+
+    fn mul(self, rhs: f64) -> Score {
+        self * Self::from_f64(rhs)
+    }
+}
+
+impl MulAssign<f64> for Score {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+/// Formats like the game does, e.g. `"123,456"`, `"1.24e11"` and `"4.6e150"`.
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_infinite() {
+            return f.write_str("naneinf");
+        }
+
+        let value = self.to_f64();
+
+        if value < Self::SCIENTIFIC_FROM {
+            let digits = format!("{value:.0}");
+            let len = digits.len();
+
+            for (i, digit) in digits.chars().enumerate() {
+                if i > 0 && (len - i) % 3 == 0 {
+                    f.write_str(",")?;
+                }
+
+                write!(f, "{digit}")?;
+            }
+
+            return Ok(());
+        }
+
+        let log10 = self.log10();
+        let mut exponent = log10.floor();
+        let mut mantissa = ((10f64.powf(log10 - exponent)) * 1000.0).round() / 1000.0;
+
+        if mantissa >= 10.0 {
+            mantissa /= 10.0;
+            exponent += 1.0;
+        }
+
+        match exponent {
+            100.0.. => write!(f, "{mantissa:.1}e{exponent}"),
+            10.0.. => write!(f, "{mantissa:.2}e{exponent}"),
+            _ => write!(f, "{mantissa:.3}e{exponent}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn x_mult() {
+        let mut mult = Mult(3.0);
+
+        mult *= 1.5;
+        mult += 4;
+        mult *= 1.5;
+
+        assert_eq!(mult, Mult(12.75));
+    }
+
+    #[test]
+    fn chips_times_mult_rounds_down() {
+        assert_eq!(Chips(15) * Mult(1.5), Score::from(22));
+        assert_eq!(Chips(10) * Mult(3.0), Score::from(30));
+    }
+
+    #[test]
+    fn exact_for_integers() {
+        for value in [0, 1, 2, 3, 300, 1234, 99_999, u32::MAX as u64, 1 << 53] {
+            assert_eq!(Score::from(value).to_f64(), value as f64);
+        }
+    }
+
+    #[test]
+    fn past_f64() {
+        let big = Score::from_f64(f64::MAX);
+        let bigger = big * big;
+
+        assert!(!bigger.is_infinite());
+        assert!(bigger > big);
+        assert_eq!(bigger.to_f64(), f64::INFINITY);
+        assert!((bigger.log10() - 2.0 * f64::MAX.log10()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn past_f64_from_chips_and_mult() {
+        let score = Chips(1_000_000) * Mult(1e305);
+
+        assert!(!score.is_infinite());
+        assert!((score.log10() - 311.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ordering() {
+        let scores = [
+            Score::ZERO,
+            Score::from_f64(0.25),
+            Score::from(1),
+            Score::from(300),
+            Score::from(301),
+            Score::from_f64(1e300) * Score::from_f64(1e300),
+            Score::INFINITY,
+        ];
+
+        for pair in scores.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} < {:?}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(Score::from(300) + Score::from(450), Score::from(750));
+        assert_eq!(Score::ZERO + Score::from(7), Score::from(7));
+        assert_eq!(Score::from(7) + Score::INFINITY, Score::INFINITY);
+
+        let big = Score::from_f64(1e300) * Score::from_f64(1e300);
+        assert_eq!(big + Score::from(1), big);
+    }
+
+    #[test]
+    fn powf() {
+        assert_eq!(Score::from(2).powf(10.0), Score::from(1024));
+        assert!((Score::from(10).powf(400.0).log10() - 400.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn naneinf() {
+        assert!(Score::from_f64(f64::INFINITY).is_infinite());
+        assert!(Score::from_f64(f64::NAN).is_infinite());
+        assert!((Chips(10) * Mult(f64::INFINITY)).is_infinite());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Score::ZERO.to_string(), "0");
+        assert_eq!(Score::from(300).to_string(), "300");
+        assert_eq!(Score::from(1234).to_string(), "1,234");
+        assert_eq!(Score::from(123_456_789).to_string(), "123,456,789");
+        assert_eq!(Score::from(99_999_999_999).to_string(), "99,999,999,999");
+        assert_eq!(Score::from(123_456_789_012).to_string(), "1.24e11");
+        assert_eq!(Score::from_f64(4.56e150).to_string(), "4.6e150");
+        assert_eq!(
+            (Score::from_f64(1e300) * Score::from_f64(2e300)).to_string(),
+            "2.0e600"
+        );
+        assert_eq!(Score::INFINITY.to_string(), "naneinf");
+    }
+}
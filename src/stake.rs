@@ -0,0 +1,25 @@
+use balatro_macros::Variants;
+use enum_assoc::Assoc;
+
+/// Each stake includes the effects of every stake below it.
+#[derive(Assoc, Variants, Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[func(pub const fn scaling(self) -> u8)]
+pub enum Stake {
+    #[default]
+    #[assoc(scaling = 1)]
+    White,
+    #[assoc(scaling = 1)]
+    Red,
+    #[assoc(scaling = 2)]
+    Green,
+    #[assoc(scaling = 2)]
+    Black,
+    #[assoc(scaling = 2)]
+    Blue,
+    #[assoc(scaling = 3)]
+    Purple,
+    #[assoc(scaling = 3)]
+    Orange,
+    #[assoc(scaling = 3)]
+    Gold,
+}
@@ -0,0 +1,51 @@
+use balatro_macros::Variants;
+use enum_assoc::Assoc;
+
+#[derive(Assoc, Variants, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[func(pub const fn name(self) -> &'static str)]
+pub enum Tarot {
+    #[assoc(name = "The Fool")]
+    Fool,
+    #[assoc(name = "The Magician")]
+    Magician,
+    #[assoc(name = "The High Priestess")]
+    HighPriestess,
+    #[assoc(name = "The Empress")]
+    Empress,
+    #[assoc(name = "The Emperor")]
+    Emperor,
+    #[assoc(name = "The Hierophant")]
+    Hierophant,
+    #[assoc(name = "The Lovers")]
+    Lovers,
+    #[assoc(name = "The Chariot")]
+    Chariot,
+    #[assoc(name = "Justice")]
+    Justice,
+    #[assoc(name = "The Hermit")]
+    Hermit,
+    #[assoc(name = "The Wheel of Fortune")]
+    WheelOfFortune,
+    #[assoc(name = "Strength")]
+    Strength,
+    #[assoc(name = "The Hanged Man")]
+    HangedMan,
+    #[assoc(name = "Death")]
+    Death,
+    #[assoc(name = "Temperance")]
+    Temperance,
+    #[assoc(name = "The Devil")]
+    Devil,
+    #[assoc(name = "The Tower")]
+    Tower,
+    #[assoc(name = "The Star")]
+    Star,
+    #[assoc(name = "The Moon")]
+    Moon,
+    #[assoc(name = "The Sun")]
+    Sun,
+    #[assoc(name = "Judgement")]
+    Judgement,
+    #[assoc(name = "The World")]
+    World,
+}